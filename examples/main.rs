@@ -1,7 +1,11 @@
 #![feature(rustc_private)]
 #![feature(box_patterns)]
 
-use rust2uml::Config;
+use std::path::Path;
+use std::time::Duration;
+
+use rust2uml::graphviz::{Engine, Output};
+use rust2uml::{Backend, Config, Direction, Focus, Format, Model, Rule};
 
 use argi::{cli, data};
 
@@ -25,17 +29,156 @@ fn main() {
         --trait_implem_bgcolor [str]: { help: "implems background color for traits" },
         --src_url_mask [str]: { help: "url mask for src links, eg http://host/crate/{file}, or 'none'" },
         --font [str]: { help: "Font name" },
+        --manifest [str]: { help: "Cargo.toml of a package or workspace to diagram instead of ./src" },
+        --per_crate [bool]: { help: "with --manifest, one diagram by crate instead of a combined one" },
+        --format [str]: { help: "dot (default, with svg), plantuml, mermaid, json, xmi, drawio, d2, graphml, structurizr, svg, html, text or package" },
+        --backend [str]: { help: "svg drawing: graphviz (default) or builtin, without external binary" },
+        --output [str]: { help: "graphviz output next to ml.dot: svg (default), png, pdf, cmapx or json" },
+        --engine [str]: { help: "graphviz layout: dot (default), fdp, sfdp or neato" },
+        --dot_binary [str]: { help: "path of the graphviz binary, dot by default" },
+        --timeout [str]: { help: "seconds after which graphviz is killed" },
+        --cluster_depth [str]: { help: "with dot, types in clusters of their modules down to this depth" },
+        --code_level [bool]: { help: "with --format structurizr, also add the types and their relations as properties of the modules" },
+        --focus [str]: { help: "only these types (paths or names, comma-separated) and their neighbours" },
+        --hops [str]: { help: "with --focus, relations followed from the types, 1 by default" },
+        --direction [str]: { help: "with --focus, relations followed: incoming, outgoing or both (default)" },
+        --public_api [bool]: { help: "only the pub types downstream crates see (pub use included), with their pub members" },
+        --include [str]: { help: "only the types matched by these rules (comma-separated), eg module:view::*,kind:struct" },
+        --exclude [str]: { help: "types left out by these rules (comma-separated), eg module:proto::*,name:/Error$/" },
+        --diff [str]: { help: "old sources to compare with, a directory or a git revision of ./src; writes ml_diff.dot and ml_diff.json" },
+        --diff_to [str]: { help: "with --diff, new sources, a directory (./src by default) or a git revision" },
+        --json [bool]: { help: "also export the model as ml.json" },
+        --from_json [str]: { help: "model (ml.json) to diagram instead of the sources" },
     )
     .launch();   
 }
 
 fn run(ctx: &argi::Command, _: Option<String>) {
-    let dest: String = concat!("target/doc/", env!("CARGO_PKG_NAME")).to_string();  
+    let dest: String = concat!("target/doc/", env!("CARGO_PKG_NAME")).to_string().replace("-", "_");
+
+    let mut renderer = rust2uml::Renderer::from(command_to_config(ctx));
+
+    match data!(ctx => --backend).map(|backend| backend.parse::<Backend>()) {
+        Some(Ok(backend)) => renderer.set_backend(backend),
+        Some(Err(e)) => {
+            eprintln!("ml: {}", e);
+            std::process::exit(1);
+        }
+        None => {}
+    }
 
-    let config = command_to_config(ctx);
-    rust2uml::Config::set_global(config);
+    match data!(ctx => --output).map(|output| output.parse::<Output>()) {
+        Some(Ok(output)) => renderer.graphviz_mut().output = output,
+        Some(Err(e)) => {
+            eprintln!("ml: {}", e);
+            std::process::exit(1);
+        }
+        None => {}
+    }
+
+    match data!(ctx => --engine).map(|engine| engine.parse::<Engine>()) {
+        Some(Ok(engine)) => renderer.graphviz_mut().engine = engine,
+        Some(Err(e)) => {
+            eprintln!("ml: {}", e);
+            std::process::exit(1);
+        }
+        None => {}
+    }
 
-    let _ = rust2uml::src2both("src", dest.replace("-", "_").as_str());
+    match data!(ctx => --dot_binary) {
+        Some(binary) => renderer.graphviz_mut().binary = binary.into(),
+        None => {}
+    }
+
+    match data!(ctx => --timeout).map(|timeout| timeout.parse::<u64>()) {
+        Some(Ok(timeout)) => renderer.graphviz_mut().timeout = Some(Duration::from_secs(timeout)),
+        Some(Err(e)) => {
+            eprintln!("ml: timeout: {}", e);
+            std::process::exit(1);
+        }
+        None => {}
+    }
+
+    // without a crate root, the sources are walked and every module counts as public.
+    if renderer.config().public_api
+        && data!(ctx => --manifest).is_none()
+        && rust2uml::module::tree::root_of("src").is_none()
+    {
+        eprintln!("ml: warning: ./src has no lib.rs nor main.rs, --public_api keeps every module");
+    }
+
+    if let Some(old) = data!(ctx => --diff) {
+        let new: String = data!(ctx => --diff_to).unwrap_or_else(|| "src".to_string());
+        // a side which isn't a directory is a revision of the repository.
+        let model = |side: &str| match Path::new(side).is_dir() {
+            true => rust2uml::parse_dir(side),
+            false => rust2uml::parse_revision(".", side, "src"),
+        };
+        let result = model(old.as_str())
+            .and_then(|old: Model| model(new.as_str()).map(|new: Model| renderer.diff(&old, &new)))
+            .and_then(|diff| renderer.diff2both(&diff, dest.as_str()));
+
+        if let Err(e) = result {
+            eprintln!("ml: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let format: Format = match data!(ctx => --format).map(|format| format.parse::<Format>()) {
+        Some(Ok(format)) => format,
+        Some(Err(e)) => {
+            eprintln!("ml: {}", e);
+            std::process::exit(1);
+        }
+        None => Format::Dot,
+    };
+
+    // the model of the diagrams, read from its JSON file, the workspace or ./src.
+    let model = || match (data!(ctx => --from_json), data!(ctx => --manifest)) {
+        (Some(json), _) => std::fs::File::open(json)
+            .map_err(rust2uml::Error::from)
+            .and_then(Model::read_json),
+        (None, Some(manifest)) => rust2uml::parse_workspace(manifest.as_str()),
+        (None, None) => rust2uml::parse_dir("src"),
+    };
+    let result = match (format, data!(ctx => --from_json), data!(ctx => --manifest)) {
+        (Format::Dot, Some(json), _) => renderer.json2both(json.as_str(), dest.as_str()),
+        (Format::Dot, None, Some(manifest)) => {
+            let per_crate = data!(bool, ctx => --per_crate).unwrap_or(false);
+            renderer.workspace2both(manifest.as_str(), dest.as_str(), per_crate)
+        }
+        (Format::Dot, None, None) => renderer.src2both("src", dest.as_str()),
+        (format, _, _) => model()
+            .and_then(|model| match (format, data!(bool, ctx => --code_level).unwrap_or(false)) {
+                (Format::Structurizr, code) => renderer.model2structurizr(&model, code),
+                (format, _) => renderer.model2format(&model, format),
+            })
+            .and_then(|content| {
+                // the text is also printed, to be read from a terminal.
+                if format == Format::Text {
+                    print!("{}", String::from_utf8_lossy(&content));
+                }
+                std::fs::create_dir_all(dest.as_str())?;
+                std::fs::write(Path::new(&dest).join("ml").with_extension(format.extension()), content)?;
+                Ok(())
+            }),
+    };
+    let result = match (result, data!(bool, ctx => --json).unwrap_or(false)) {
+        // the model as drawn: selected, filtered and focused like the diagrams.
+        (Ok(()), true) => model()
+            .and_then(|model| renderer.model2format(&model, Format::Json))
+            .and_then(|content| {
+                std::fs::write(Path::new(&dest).join(rust2uml::DEFAULT_NAME_JSON), content)?;
+                Ok(())
+            }),
+        (result, _) => result,
+    };
+
+    if let Err(e) = result {
+        eprintln!("ml: {}", e);
+        std::process::exit(1);
+    }
 }
 
 fn command_to_config(ctx: &argi::Command) -> Config {
@@ -123,5 +266,54 @@ fn command_to_config(ctx: &argi::Command) -> Config {
         None => {},
     }
 
+    match (data!(ctx => --focus), data!(ctx => --hops).map(|hops| hops.parse::<usize>())) {
+        (Some(_), Some(Err(e))) => {
+            eprintln!("ml: hops: {}", e);
+            std::process::exit(1);
+        }
+        (Some(roots), hops) => match data!(ctx => --direction).map(|direction| direction.parse::<Direction>()) {
+            Some(Err(e)) => {
+                eprintln!("ml: {}", e);
+                std::process::exit(1);
+            }
+            direction => {
+                config.focus = Some(Focus {
+                    roots: roots.split(',').map(|root| root.trim().to_string()).collect::<Vec<String>>(),
+                    hops: hops.and_then(|hops| hops.ok()).unwrap_or(1),
+                    direction: direction.and_then(|direction| direction.ok()).unwrap_or_default(),
+                })
+            }
+        },
+        (None, _) => {},
+    }
+
+    match data!(bool, ctx => --public_api) {
+        Some(v) => config.public_api = v,
+        None => {},
+    }
+
+    for (rules, filter) in [
+        (data!(ctx => --include), &mut config.filter.include),
+        (data!(ctx => --exclude), &mut config.filter.exclude),
+    ] {
+        match rules.map(|rules| Rule::list(&rules)) {
+            Some(Ok(rules)) => filter.extend(rules),
+            Some(Err(e)) => {
+                eprintln!("ml: {}", e);
+                std::process::exit(1);
+            }
+            None => {},
+        }
+    }
+
+    match data!(ctx => --cluster_depth).map(|depth| depth.parse::<usize>()) {
+        Some(Ok(depth)) => config.cluster_depth = Some(depth),
+        Some(Err(e)) => {
+            eprintln!("ml: cluster_depth: {}", e);
+            std::process::exit(1);
+        }
+        None => {},
+    }
+
     config
 }
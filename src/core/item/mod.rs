@@ -34,19 +34,22 @@ impl<'a> Iterator for Item<'a> {
         self.it.next().and_then(|item| {
             let mut list: Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)> = vec![item];
 
-            // Loop over all Items to find any Impl with a name that matches our name.
+            // Loop over all Items to find any Impl of a type which resolves to our path.
             // This way we can handle cases like:
             //      struct Foo{}
             //      struct Bar{}
             //      impl Foo {...}
             //      impl Bar {...}
             //      impl Foo {...}
-            let item_name = &item.0.ident.name;
+            // without mixing up the `Foo`s declared by different modules.
+            let mut item_path: Vec<String> = item.1.segments();
+
+            item_path.push(item.0.ident.name.to_string());
             list.extend(
                 self.it
                     .clone()
                     .filter(
-                        |&&(ref subitem, _): &&'a (ptr::P<ast::Item>, Rc<ModulePath>)| {
+                        |&&(ref subitem, ref subpath): &&'a (ptr::P<ast::Item>, Rc<ModulePath>)| {
                             if let &ast::ItemKind::Impl(box ast::Impl {
                                 self_ty: ref ty, ..
                             }) = &subitem.kind
@@ -62,7 +65,13 @@ impl<'a> Iterator for Item<'a> {
                                     ..
                                 } = &**ty
                                 {
-                                    if !seg.is_empty() && &seg[0].ident.name == item_name {
+                                    let ty_path: String = seg
+                                        .iter()
+                                        .map(|segment| segment.ident.name.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join("::");
+
+                                    if !seg.is_empty() && subpath.resolves_to(&ty_path, &item_path) {
                                         return true;
                                     }
                                 }
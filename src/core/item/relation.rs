@@ -1,9 +1,11 @@
 use super::ItemState;
 
 use dot::{ArrowShape, Fill, Side};
+use serde::{Deserialize, Serialize};
 
 /// The enumeration `Relation` is the relationship specification from [UML 2.5](http://www.omg.org/spec/UML/2.5) without generalization.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Relation {
     Association,
     Aggregation,
@@ -25,6 +27,18 @@ impl Relation {
             &Relation::None => ArrowShape::NoArrow,
         }
     }
+
+    /// The method `as_str` returns the name of the relationship, as it's serialized.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            &Relation::Association => "association",
+            &Relation::Aggregation => "aggregation",
+            &Relation::Composition => "composition",
+            &Relation::Realization => "realization",
+            &Relation::Dependency => "dependency",
+            &Relation::None => "none",
+        }
+    }
 }
 
 impl<'a> From<(&'a ItemState<'a>, &'a ItemState<'a>)> for Relation {
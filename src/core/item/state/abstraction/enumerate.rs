@@ -9,7 +9,7 @@ use rustc_span::symbol;
 use crate::module::path::ModulePath;
 
 use crate::dot::escape_html;
-use crate::Config;
+use crate::{Config, WithConfig};
 
 /// The structure `Enum` is a enumerate abstract element.
 #[derive(Debug, Clone)]
@@ -119,23 +119,25 @@ impl<'a>
     }
 }
 
-impl<'a> fmt::Display for Enum<'a> {
+impl<'c, 'a> fmt::Display for WithConfig<'c, Enum<'a>> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let include_variants = !self.variants.is_empty() && Config::global().include_fields;
+        let &WithConfig(item, config) = self;
+
+        let include_variants = !item.variants.is_empty() && config.include_fields;
 
         if !include_variants {
             write!(
                 f,
                 "<tr><td bgcolor=\"{bgcolor}\"><b>{name}</b></td></tr>",
-                bgcolor = Config::global().enum_header_bgcolor,
-                name = self.name
+                bgcolor = config.enum_header_bgcolor,
+                name = item.name
             )
         } else {
             write!(f, "<tr><td bgcolor=\"{header_bgcolor}\"><b>{name}</b></td></tr><tr><td align=\"left\" bgcolor=\"{fields_bgcolor}\">{variants}<br align=\"left\"/></td></tr>",
-                header_bgcolor = Config::global().enum_header_bgcolor,
-                fields_bgcolor = Config::global().enum_fields_bgcolor,
-                name = self.name,
-                variants = self.variants.iter()
+                header_bgcolor = config.enum_header_bgcolor,
+                fields_bgcolor = config.enum_fields_bgcolor,
+                name = item.name,
+                variants = item.variants.iter()
                                            .map(|&(ref name, ref struct_field): &(symbol::Symbol, Vec<String>)|
                                                 if struct_field.is_empty() {
                                                     escape_html(&format!("{}", name))
@@ -150,3 +152,9 @@ impl<'a> fmt::Display for Enum<'a> {
         }
     }
 }
+
+impl<'a> fmt::Display for Enum<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", WithConfig(self, &Config::global()))
+    }
+}
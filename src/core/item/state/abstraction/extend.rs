@@ -8,7 +8,7 @@ use rustc_ast_pretty::pprust::ty_to_string;
 use rustc_span::symbol;
 
 use crate::module::path::ModulePath;
-use crate::Config;
+use crate::{Config, WithConfig};
 
 use crate::dot::escape_html;
 
@@ -116,14 +116,16 @@ impl<'a>
     }
 }
 
-impl<'a> fmt::Display for Trait<'a> {
+impl<'c, 'a> fmt::Display for WithConfig<'c, Trait<'a>> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if Config::global().include_methods {
+        let &WithConfig(item, config) = self;
+
+        if config.include_methods {
             write!(f, "<tr><td bgcolor=\"{header_bgcolor}\"><b>{name}</b></td></tr><tr><td align=\"left\" bgcolor=\"{method_bgcolor}\">{items}<br align=\"left\"/></td></tr>",
-            header_bgcolor = Config::global().trait_header_bgcolor,
-            method_bgcolor = Config::global().trait_method_bgcolor,
-            name = self.name,
-            items = self.items.iter()
+            header_bgcolor = config.trait_header_bgcolor,
+            method_bgcolor = config.trait_method_bgcolor,
+            name = item.name,
+            items = item.items.iter()
                                     .map(|&(ref name, ref ty, ref ret): &(symbol::Symbol, Vec<String>, String)|
                                             escape_html(&format!("{name}({ty}) -> {ret}",
                                                 name = name,
@@ -138,9 +140,15 @@ impl<'a> fmt::Display for Trait<'a> {
             write!(
                 f,
                 "<tr><td bgcolor=\"{bgcolor}\"><b>{name}</b></td></tr>",
-                bgcolor = Config::global().trait_header_bgcolor,
-                name = self.name,
+                bgcolor = config.trait_header_bgcolor,
+                name = item.name,
             )
         }
     }
 }
+
+impl<'a> fmt::Display for Trait<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", WithConfig(self, &Config::global()))
+    }
+}
@@ -11,6 +11,7 @@ use rustc_ast::{ast, ptr};
 use rustc_span::symbol;
 
 use crate::module::path::ModulePath;
+use crate::{Config, WithConfig};
 
 use self::enumerate::Enum;
 use self::extend::Trait;
@@ -137,8 +138,26 @@ impl<'a>
     }
 }
 
-impl<'a> From<((&'a ast::Item, &'a ThinVec<ast::FieldDef>), Rc<ModulePath>)> for Abstract<'a> {
-    fn from(arguments: ((&'a ast::Item, &'a ThinVec<ast::FieldDef>), Rc<ModulePath>)) -> Abstract<'a> {
+impl<'a>
+    From<(
+        (
+            &'a ast::Item,
+            &'a ThinVec<ast::GenericParam>,
+            &'a ThinVec<ast::FieldDef>,
+        ),
+        Rc<ModulePath>,
+    )> for Abstract<'a>
+{
+    fn from(
+        arguments: (
+            (
+                &'a ast::Item,
+                &'a ThinVec<ast::GenericParam>,
+                &'a ThinVec<ast::FieldDef>,
+            ),
+            Rc<ModulePath>,
+        ),
+    ) -> Abstract<'a> {
         Abstract::Struct(Struct::from(arguments))
     }
 }
@@ -167,13 +186,21 @@ impl<'a>
     }
 }
 
-impl<'a> fmt::Display for Abstract<'a> {
+impl<'c, 'a> fmt::Display for WithConfig<'c, Abstract<'a>> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            &Abstract::Struct(ref item) => write!(f, "{}", item),
-            &Abstract::Enum(ref item) => write!(f, "{}", item),
-            &Abstract::Trait(ref item) => write!(f, "{}", item),
+        let &WithConfig(item, config) = self;
+
+        match item {
+            &Abstract::Struct(ref item) => write!(f, "{}", WithConfig(item, config)),
+            &Abstract::Enum(ref item) => write!(f, "{}", WithConfig(item, config)),
+            &Abstract::Trait(ref item) => write!(f, "{}", WithConfig(item, config)),
             &Abstract::None => Err(fmt::Error),
         }
     }
 }
+
+impl<'a> fmt::Display for Abstract<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", WithConfig(self, &Config::global()))
+    }
+}
@@ -9,7 +9,7 @@ use rustc_span::symbol;
 use crate::module::path::ModulePath;
 
 use crate::dot::escape_html;
-use crate::Config;
+use crate::{Config, WithConfig};
 
 /// The structure `Struct` is a structure abstract element.
 
@@ -21,6 +21,7 @@ pub struct Struct<'a> {
     /// Visibility
     pub vis: &'a ast::VisibilityKind,
     pub name: symbol::Symbol,
+    pub params: ThinVec<symbol::Symbol>,
     pub fields: ThinVec<(&'a ast::VisibilityKind, Option<symbol::Symbol>, String)>,
 }
 
@@ -38,21 +39,47 @@ impl<'a> PartialEq for Struct<'a> {
 
         // ignore fields for now, because it contains Visibility
 
-        a.path == b.path && a.name == b.name && bvis
+        a.path == b.path && a.name == b.name && a.params == b.params && bvis
     }
 }
 
 impl<'a> Eq for Struct<'a> {}
 
-impl<'a> From<((&'a ast::Item, &'a ThinVec<ast::FieldDef>), Rc<ModulePath>)> for Struct<'a> {
+impl<'a>
+    From<(
+        (
+            &'a ast::Item,
+            &'a ThinVec<ast::GenericParam>,
+            &'a ThinVec<ast::FieldDef>,
+        ),
+        Rc<ModulePath>,
+    )> for Struct<'a>
+{
     fn from(
-        ((item, struct_field), path): ((&'a ast::Item, &'a ThinVec<ast::FieldDef>), Rc<ModulePath>),
+        ((item, params, struct_field), path): (
+            (
+                &'a ast::Item,
+                &'a ThinVec<ast::GenericParam>,
+                &'a ThinVec<ast::FieldDef>,
+            ),
+            Rc<ModulePath>,
+        ),
     ) -> Struct<'a> {
         Struct {
             path: path,
             span: item.span,
             vis: &item.vis.kind,
             name: item.ident.name,
+            params: params
+                .iter()
+                .map(
+                    |&ast::GenericParam {
+                         attrs: _,
+                         ident: symbol::Ident { name, .. },
+                         ..
+                     }| name,
+                )
+                .collect::<ThinVec<symbol::Symbol>>(),
             fields: struct_field
                 .iter()
                 .filter_map(
@@ -73,23 +100,25 @@ impl<'a> From<((&'a ast::Item, &'a ThinVec<ast::FieldDef>), Rc<ModulePath>)> for
     }
 }
 
-impl<'a> fmt::Display for Struct<'a> {
+impl<'c, 'a> fmt::Display for WithConfig<'c, Struct<'a>> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let include_fields = !self.fields.is_empty() && Config::global().include_fields;
+        let &WithConfig(item, config) = self;
+
+        let include_fields = !item.fields.is_empty() && config.include_fields;
 
         if !include_fields {
             write!(
                 f,
                 "<tr><td bgcolor=\"{bgcolor}\"><b>{name}</b></td></tr>",
-                bgcolor = Config::global().struct_header_bgcolor,
-                name = self.name
+                bgcolor = config.struct_header_bgcolor,
+                name = item.name
             )
         } else {
             write!(f, "<tr><td bgcolor=\"{header_bgcolor}\"><b>{name}</b></td></tr><tr><td align=\"left\" bgcolor=\"{fields_bgcolor}\">{fields}<br align=\"left\"/></td></tr>",
-                header_bgcolor = Config::global().struct_header_bgcolor,
-                fields_bgcolor = Config::global().struct_fields_bgcolor,
-                name = self.name,
-                fields = self.fields.iter()
+                header_bgcolor = config.struct_header_bgcolor,
+                fields_bgcolor = config.struct_fields_bgcolor,
+                name = item.name,
+                fields = item.fields.iter()
                                                 .map(|&(ref vis, ref name, ref ty): &(&ast::VisibilityKind, Option<symbol::Symbol>, String)|{
                                                     let name_part = match name {
                                                         Some(n) => format!("{}: ", n),
@@ -108,3 +137,9 @@ impl<'a> fmt::Display for Struct<'a> {
         }
     }
 }
+
+impl<'a> fmt::Display for Struct<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", WithConfig(self, &Config::global()))
+    }
+}
@@ -1,6 +1,7 @@
 use super::DEFAULT_FUNC;
 
 use std::fmt;
+use std::rc::Rc;
 use thin_vec::ThinVec;
 
 use rustc_ast::ast;
@@ -8,31 +9,37 @@ use rustc_ast_pretty::pprust::ty_to_string;
 use rustc_span::symbol;
 
 use crate::dot::escape_html;
+use crate::module::path::ModulePath;
 
 /// The structure `Implem` is a collection of methods and tyes for an abstract element.
 
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct Implem {
-    ty: ThinVec<(symbol::Symbol, Vec<String>)>,
+    pub(crate) ty: ThinVec<(symbol::Symbol, Vec<String>)>,
     /// method's name, arguments, result.
-    method: ThinVec<(symbol::Symbol, Vec<String>, Option<String>)>,
+    pub(crate) method: ThinVec<(symbol::Symbol, Vec<String>, Option<String>)>,
+    /// Module of the implementation, which resolves its paths.
+    pub(crate) path: Rc<ModulePath>,
 }
 
 impl Implem {
-    pub fn is_realization(&self, ty_name: &String) -> bool {
-        if let Some(&(ref name, _)) = self.ty.first() {
-            name.to_string().eq(ty_name)
-        } else {
-            false
-        }
+    pub fn is_realization(&self, ty_path: &[String]) -> bool {
+        let trait_path: String = self
+            .ty
+            .iter()
+            .map(|&(ref name, _)| name.to_string())
+            .collect::<Vec<String>>()
+            .join("::");
+
+        !self.ty.is_empty() && self.path.resolves_to(&trait_path, ty_path)
     }
 
-    pub fn is_association(&self, ty_name: &String) -> bool {
+    pub fn is_association(&self, ty_path: &[String]) -> bool {
         self.method.iter().any(
             |&(_, _, ref result): &(symbol::Symbol, Vec<String>, Option<String>)| {
                 if let &Some(ref ret) = result {
                     ret.split(|at| "<[(;, )]>".contains(at))
-                        .any(|ty| ty.eq(ty_name))
+                        .any(|ty| self.path.resolves_to(ty, ty_path))
                 } else {
                     false
                 }
@@ -40,7 +47,7 @@ impl Implem {
         )
     }
 
-    pub fn is_dependency(&self, _: &String) -> bool {
+    pub fn is_dependency(&self, _: &[String]) -> bool {
         false
         /*self.method.iter()
         .any(|&( _, ref arg, _): &(symbol::Symbol, Vec<String>, Option<String>)|
@@ -52,23 +59,28 @@ impl
     From<(
         ThinVec<(symbol::Symbol, Vec<String>)>,
         ThinVec<(symbol::Symbol, Vec<String>, Option<String>)>,
+        Rc<ModulePath>,
     )> for Implem
 {
     fn from(
-        (ty, method): (
+        (ty, method, path): (
             ThinVec<(symbol::Symbol, Vec<String>)>,
             ThinVec<(symbol::Symbol, Vec<String>, Option<String>)>,
+            Rc<ModulePath>,
         ),
     ) -> Implem {
         Implem {
             ty: ty,
             method: method,
+            path: path,
         }
     }
 }
 
-impl<'a> From<(&'a ThinVec<ast::PathSegment>, &'a ThinVec<ast::Item>)> for Implem {
-    fn from((segments, impl_item): (&'a ThinVec<ast::PathSegment>, &'a ThinVec<ast::Item>)) -> Implem {
+impl<'a> From<((&'a ThinVec<ast::PathSegment>, &'a ThinVec<ast::Item>), Rc<ModulePath>)> for Implem {
+    fn from(
+        ((segments, impl_item), path): ((&'a ThinVec<ast::PathSegment>, &'a ThinVec<ast::Item>), Rc<ModulePath>),
+    ) -> Implem {
         Implem::from((
             segments
                 .iter()
@@ -145,6 +157,7 @@ impl<'a> From<(&'a ThinVec<ast::PathSegment>, &'a ThinVec<ast::Item>)> for Imple
                     },
                 )
                 .collect::<ThinVec<(symbol::Symbol, Vec<String>, Option<String>)>>(),
+            path,
         ))
     }
 }
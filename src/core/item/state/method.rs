@@ -17,13 +17,13 @@ use crate::dot::escape_html;
 #[derive(Default, Debug, Clone)]
 pub struct Method {
     /// visibility, method's name, arguments, result.
-    func: Vec<(
+    pub(crate) func: Vec<(
         ast::VisibilityKind,
         symbol::Symbol,
         Vec<String>,
         Option<String>,
     )>,
-    path: Rc<ModulePath>,
+    pub(crate) path: Rc<ModulePath>,
 }
 
 impl PartialEq for Method {
@@ -48,7 +48,7 @@ impl PartialEq for Method {
 impl Eq for Method {}
 
 impl Method {
-    pub fn is_association(&self, ty_name: &String) -> bool {
+    pub fn is_association(&self, ty_path: &[String]) -> bool {
         self.func.iter().any(
             |&(_, _, _, ref result): &(
                 ast::VisibilityKind,
@@ -58,7 +58,7 @@ impl Method {
             )| {
                 if let &Some(ref ret) = result {
                     ret.split(|at| "<[(;, )]>".contains(at))
-                        .any(|ty| ty.eq(ty_name))
+                        .any(|ty| self.path.resolves_to(ty, ty_path))
                 } else {
                     false
                 }
@@ -66,14 +66,19 @@ impl Method {
         )
     }
 
-    pub fn is_dependency(&self, name: &String) -> bool {
+    pub fn is_dependency(&self, ty_path: &[String]) -> bool {
         self.func.iter().any(
             |&(_, _, ref arg, _): &(
                 ast::VisibilityKind,
                 symbol::Symbol,
                 Vec<String>,
                 Option<String>,
-            )| arg.iter().any(|ty| ty.ends_with(name)),
+            )| {
+                arg.iter().any(|ty| {
+                    ty.split(|at| "<[(;, )]>&".contains(at))
+                        .any(|ty| self.path.resolves_to(ty, ty_path))
+                })
+            },
         )
     }
 }
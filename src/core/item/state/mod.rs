@@ -3,9 +3,9 @@ pub mod implem;
 pub mod method;
 
 #[cfg(not(feature = "fn-emilgardis"))]
-const DEFAULT_FUNC: &'static str = " ";
+pub(crate) const DEFAULT_FUNC: &'static str = " ";
 #[cfg(feature = "fn-emilgardis")]
-const DEFAULT_FUNC: &'static str = " fn ";
+pub(crate) const DEFAULT_FUNC: &'static str = " fn ";
 
 use self::abstraction::Abstract;
 use self::implem::Implem;
@@ -22,8 +22,9 @@ use thin_vec::{thin_vec, ThinVec};
 use rustc_ast::{ast, ptr};
 use rustc_span::symbol::Symbol;
 
+use crate::dot::escape_html;
 use crate::module::path::ModulePath;
-use crate::Config;
+use crate::{Config, WithConfig};
 
 /// The structure `ItemState` describes an abstract element with a collections of methodes
 /// and implementations.
@@ -32,9 +33,9 @@ pub struct ItemState<'a> {
     /// Data Type.
     pub(crate) node: Abstract<'a>,
     /// Implementation of Method.
-    method: Vec<Method>,
+    pub(crate) method: Vec<Method>,
     /// Implementation of Trait.
-    implem: Vec<Implem>,
+    pub(crate) implem: Vec<Implem>,
 }
 
 impl<'a> ItemState<'a> {
@@ -43,16 +44,14 @@ impl<'a> ItemState<'a> {
     }
 
     pub fn is_association(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ref ty_name: String = name.to_string();
-
+        if let Some(ref ty_path) = self.as_path() {
             rhs.method
                 .iter()
-                .any(|func| func.is_association(ty_name))
+                .any(|func| func.is_association(ty_path))
                 .bitor(
                     rhs.implem
                         .iter()
-                        .any(|implem| implem.is_association(&ty_name)),
+                        .any(|implem| implem.is_association(ty_path)),
                 )
         } else {
             false
@@ -60,16 +59,14 @@ impl<'a> ItemState<'a> {
     }
 
     pub fn is_dependency(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ref ty_name: String = name.to_string();
-
+        if let Some(ref ty_path) = self.as_path() {
             rhs.method
                 .iter()
-                .any(|method| method.is_dependency(&ty_name))
+                .any(|method| method.is_dependency(ty_path))
                 .bitor(
                     self.implem
                         .iter()
-                        .any(|implem| implem.is_dependency(&ty_name)),
+                        .any(|implem| implem.is_dependency(ty_path)),
                 )
         } else {
             false
@@ -77,16 +74,12 @@ impl<'a> ItemState<'a> {
     }
 
     pub fn is_aggregation(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let mut ty_name_mut: String = String::from("*mut ");
-            let mut ty_name_const: String = String::from("*const ");
-
-            ty_name_mut.push_str(&name.as_str());
-            ty_name_const.push_str(&name.as_str());
+        if let (Some(ref ty_path), Some(scope)) = (self.as_path(), rhs.node.path()) {
             rhs.node.into_iter().any(|attribut: &String| {
                 attribut
                     .split(|at| "<[(;,)]>".contains(at))
-                    .any(|ty| ty_name_mut.eq(ty).bitor(ty_name_const.eq(ty)))
+                    .filter_map(|ty| ty.strip_prefix("*mut ").or_else(|| ty.strip_prefix("*const ")))
+                    .any(|ty| scope.resolves_to(ty, ty_path))
             })
         } else {
             false
@@ -94,13 +87,11 @@ impl<'a> ItemState<'a> {
     }
 
     pub fn is_composition(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ty_name: String = name.to_string();
-
+        if let (Some(ref ty_path), Some(scope)) = (self.as_path(), rhs.node.path()) {
             rhs.node.into_iter().any(|attribut: &String| {
                 attribut
                     .split(|at| "<[(;,)]>".contains(at))
-                    .any(|ty| ty.eq(&ty_name))
+                    .any(|ty| scope.resolves_to(ty, ty_path))
             })
         } else {
             false
@@ -108,12 +99,10 @@ impl<'a> ItemState<'a> {
     }
 
     pub fn is_realization(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ty_name: String = name.to_string();
-
+        if let Some(ref ty_path) = self.as_path() {
             rhs.implem
                 .iter()
-                .any(|implem| implem.is_realization(&ty_name))
+                .any(|implem| implem.is_realization(ty_path))
         } else {
             false
         }
@@ -131,6 +120,20 @@ impl<'a> ItemState<'a> {
         self.node.as_name()
     }
 
+    /// The method `as_path` returns the crate's tag, the module's segments and the name
+    /// of the element, which identify it across the modules.
+    pub fn as_path(&self) -> Option<Vec<String>> {
+        match (self.node.path(), self.node.as_name()) {
+            (Some(path), Some(name)) => {
+                let mut segments: Vec<String> = path.segments();
+
+                segments.push(name.to_string());
+                Some(segments)
+            }
+            _ => None,
+        }
+    }
+
     pub fn as_arrow(&self, rhs: &ItemState<'a>) -> Relation {
         Relation::from((self, rhs))
     }
@@ -157,7 +160,7 @@ impl<'a> From<(Abstract<'a>, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>)> for
             implem: properties
                 .iter()
                 .filter_map(
-                    |&&(ref item, _): &&'a (ptr::P<ast::Item>, Rc<ModulePath>)| {
+                    |&&(ref item, ref path): &&'a (ptr::P<ast::Item>, Rc<ModulePath>)| {
                         if let ast::ItemKind::Impl(b) = &item.kind {
                             let ast::Impl { of_trait, .. } = &**b;
                             if let Some(ast::TraitRef {
@@ -170,7 +173,7 @@ impl<'a> From<(Abstract<'a>, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>)> for
                                 ..
                             }) = of_trait
                             {
-                                Some(Implem::from((segments, &thin_vec![(**item).clone()])))
+                                Some(Implem::from(((segments, &thin_vec![(**item).clone()]), Rc::clone(path))))
                             } else {
                                 None
                             }
@@ -212,10 +215,13 @@ impl<'a> From<Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>> for ItemState<'a> {
                         // Structure with variables.
                         &ast::ItemKind::Struct(
                             ast::VariantData::Struct { ref fields, ..},
-                            ..,
+                            ast::Generics { ref params, .. },
                         ) => {
-                            let kind: (&'a ast::Item, &'a ThinVec<ast::FieldDef>) =
-                                (item, fields);
+                            let kind: (
+                                &'a ast::Item,
+                                &'a ThinVec<ast::GenericParam>,
+                                &'a ThinVec<ast::FieldDef>,
+                            ) = (item, params, fields);
                             let kind: (Abstract, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>) =
                                 (Abstract::from((kind, Rc::clone(path))), properties.to_vec());
                             Some(ItemState::from(kind))
@@ -223,10 +229,13 @@ impl<'a> From<Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>> for ItemState<'a> {
                         // Structure (tuple)
                         &ast::ItemKind::Struct(
                             ast::VariantData::Tuple(ref struct_field, _),
-                            ..,
+                            ast::Generics { ref params, .. },
                         ) => {
-                            let kind: (&'a ast::Item, &'a ThinVec<ast::FieldDef>) =
-                                (item, struct_field);
+                            let kind: (
+                                &'a ast::Item,
+                                &'a ThinVec<ast::GenericParam>,
+                                &'a ThinVec<ast::FieldDef>,
+                            ) = (item, params, struct_field);
                             let kind: (Abstract, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>) =
                                 (Abstract::from((kind, Rc::clone(path))), properties.to_vec());
                             Some(ItemState::from(kind))
@@ -253,16 +262,18 @@ impl<'a> From<Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>> for ItemState<'a> {
     }
 }
 
-impl<'a> fmt::Display for ItemState<'a> {
+impl<'c, 'a> fmt::Display for WithConfig<'c, ItemState<'a>> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let href = match Config::global().src_url_mask.is_empty() {
+        let &WithConfig(item, config) = self;
+
+        let href = match config.src_url_mask.is_empty() {
             true => "".to_string(),
             false => {
-                match self.node.path() {
+                match item.node.path() {
                     Some(path) => {
                         // scrolltext is for finding eg 'struct MyStruct' in the source file
                         // using the scroll-to-text-fragment feature in chromium browsers.
-                        let scrolltext = match (self.node.as_type(), self.node.as_name()) {
+                        let scrolltext = match (item.node.as_type(), item.node.as_name()) {
                             (Some(ty), Some(name)) => {
                                 let searchtext = format!("{} {}", ty, name);
                                 format!("#:~:text={}", urlencoding::encode(&searchtext))
@@ -270,19 +281,13 @@ impl<'a> fmt::Display for ItemState<'a> {
                             _ => "".to_string(),
                         };
 
-                        // figure out path to file from crate root.
-                        let fpath = (**path)
-                            .path
-                            .iter()
-                            .map(|p| p.clone().into_string().unwrap())
-                            .collect::<Vec<String>>()
-                            .join("/");
+                        // the file which declares the module, from crate root.
                         let mut vars = HashMap::new();
-                        let file = format!("src/{}.rs", fpath);
+                        let file = path.file.to_string_lossy().replace("\\", "/");
 
                         // insert file into src_url_mask
                         vars.insert("file".to_string(), file.as_str());
-                        match strfmt::strfmt(&Config::global().src_url_mask, &vars) {
+                        match strfmt::strfmt(&config.src_url_mask, &vars) {
                             Ok(url) => format!(" href=\"{}{}\"", url, scrolltext),
                             Err(e) => {
                                 eprintln!("invalid src_url_mask. error: {}", e.to_string());
@@ -295,45 +300,55 @@ impl<'a> fmt::Display for ItemState<'a> {
             }
         };
 
+        // the crate's tag, for the diagrams of a whole workspace.
+        let krate = match item.node.path() {
+            Some(path) if !path.krate.is_empty() => format!(
+                "<tr><td><font point-size=\"9\">{krate}</font></td></tr>",
+                krate = escape_html(&path.krate)
+            ),
+            _ => "".to_string(),
+        };
+
         write!(f, 
-                "<font face=\"{font}\"><table border=\"1\" cellspacing=\"0\" cellpadding=\"10\"{href}>{node}",
+                "<font face=\"{font}\"><table border=\"1\" cellspacing=\"0\" cellpadding=\"10\"{href}>{krate}{node}",
                 href = href,
-                font = Config::global().font_name,
-                node = self.node,
+                font = config.font_name,
+                krate = krate,
+                node = WithConfig(&item.node, config),
         )?;
 
-        let include_method = !self.method.is_empty() && Config::global().include_methods;
+        let include_method = !item.method.is_empty() && config.include_methods;
 
         if include_method {
-            let bgcolor = match self.node {
-                Abstract::Struct { .. } => Config::global().struct_method_bgcolor.clone(),
-                Abstract::Trait { .. } => Config::global().trait_method_bgcolor.clone(),
-                Abstract::Enum { .. } => Config::global().enum_method_bgcolor.clone(),
+            let bgcolor = match item.node {
+                Abstract::Struct { .. } => config.struct_method_bgcolor.clone(),
+                Abstract::Trait { .. } => config.trait_method_bgcolor.clone(),
+                Abstract::Enum { .. } => config.enum_method_bgcolor.clone(),
                 Abstract::None => "white".to_string(),
             };
 
             write!(f, "<tr><td align=\"left\" bgcolor=\"{bgcolor}\">{method}<br align=\"left\"/></td></tr>",
                 bgcolor = bgcolor,
-                method = self.method.iter()
+                method = item.method.iter()
                                     .map(|ref methods| format!("{}", methods))
                                     .collect::<Vec<String>>().join("<br align=\"left\"/>\n").as_str())?;
         }
 
-        let include_implem = !self.implem.is_empty() && Config::global().include_implems;
+        let include_implem = !item.implem.is_empty() && config.include_implems;
 
         if include_implem {
             // Config::global().include_implem {
 
-            let bgcolor = match self.node {
-                Abstract::Struct { .. } => Config::global().struct_implem_bgcolor.clone(),
-                Abstract::Trait { .. } => Config::global().trait_implem_bgcolor.clone(),
-                Abstract::Enum { .. } => Config::global().enum_implem_bgcolor.clone(),
+            let bgcolor = match item.node {
+                Abstract::Struct { .. } => config.struct_implem_bgcolor.clone(),
+                Abstract::Trait { .. } => config.trait_implem_bgcolor.clone(),
+                Abstract::Enum { .. } => config.enum_implem_bgcolor.clone(),
                 Abstract::None => "white".to_string(),
             };
 
             write!(f, "<tr><td align=\"left\" bgcolor=\"{bgcolor}\">{implem}<br align=\"left\"/></td></tr>",
                 bgcolor = bgcolor,
-                implem = self.implem.iter()
+                implem = item.implem.iter()
                                     .map(|ref implem| format!("{}", implem))
                                     .collect::<Vec<String>>().join("<br align=\"left\"/>\n").as_str())?;
         }
@@ -341,3 +356,9 @@ impl<'a> fmt::Display for ItemState<'a> {
         write!(f, "</table></font>")
     }
 }
+
+impl<'a> fmt::Display for ItemState<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", WithConfig(self, &Config::global()))
+    }
+}
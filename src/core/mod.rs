@@ -17,25 +17,55 @@ use crate::itertools::Itertools;
 use rustc_ast::{ast, ptr};
 
 use crate::module::path::ModulePath;
+use crate::{Config, WithConfig};
+
+/// The function `path2id` returns the identifier of the node of path `path`
+/// (see `ItemState::as_path`), which is valid for every backend.
+///
+/// The segments are joined by `__`, and any other character than an ASCII letter or digit
+/// is written `_<hex>_` (`_5f_` for `_`, `_3a_` for `:`), so two paths never share an id.
+pub(crate) fn path2id(path: &[String]) -> String {
+    format!(
+        "nd{}",
+        path.iter()
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                segment
+                    .chars()
+                    .map(|at: char| match at.is_ascii_alphanumeric() {
+                        true => at.to_string(),
+                        false => format!("_{:x}_", at as u32),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("__")
+    )
+}
 
 #[derive(Debug, Clone)]
 pub struct ListItem<'a> {
     parse: Item<'a>,
+    /// Configuration of the labels.
+    config: &'a Config,
 }
 
-impl<'a> From<Item<'a>> for ListItem<'a> {
-    fn from(parse: Item<'a>) -> ListItem<'a> {
-        ListItem { parse: parse }
+impl<'a> From<(Item<'a>, &'a Config)> for ListItem<'a> {
+    fn from((parse, config): (Item<'a>, &'a Config)) -> ListItem<'a> {
+        ListItem {
+            parse: parse,
+            config: config,
+        }
     }
 }
 
-impl<'a> From<iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>>
+impl<'a> From<(iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>, &'a Config)>
     for ListItem<'a>
 {
     fn from(
-        list: iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>,
+        (list, config): (iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>, &'a Config),
     ) -> ListItem<'a> {
-        ListItem::from(Item::from(list))
+        ListItem::from((Item::from(list), config))
     }
 }
 
@@ -43,9 +73,15 @@ impl<'a> Iterator for ListItem<'a> {
     type Item = ItemState<'a>;
 
     fn next(&mut self) -> Option<ItemState<'a>> {
+        let config: &Config = self.config;
+
+        // the types left out by the filter are skipped before their relations are computed.
         self.parse
             .by_ref()
-            .skip_while(|state| state.is_none())
+            .skip_while(|state| match (state.as_path(), state.node.as_type()) {
+                (Some(path), Some(kind)) => !config.filter.accepts(&path, kind),
+                _ => true,
+            })
             .next()
     }
 }
@@ -56,8 +92,8 @@ impl<'a> Labeller<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
     }
 
     fn node_id(&'a self, state: &ItemState<'a>) -> Id<'a> {
-        match state.as_name() {
-            Some(name) => Id::new(format!("nd{}", name)).unwrap(),
+        match state.as_path() {
+            Some(path) => Id::new(path2id(&path)).unwrap(),
             _ => unreachable!(),
         }
     }
@@ -67,7 +103,7 @@ impl<'a> Labeller<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
     }
 
     fn node_label(&'a self, state: &ItemState<'a>) -> LabelText<'a> {
-        LabelText::HtmlStr(format!("{}", state).into())
+        LabelText::HtmlStr(format!("{}", WithConfig(state, self.config)).into())
     }
 
     fn edge_color(&'a self, _state: &Segment<'a>) -> Option<LabelText<'a>> {
@@ -116,20 +152,7 @@ impl<'a> GraphWalk<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
                 .collect::<Vec<Vec<Segment<'a>>>>()
                 .concat()
                 .into_iter()
-                .unique_by(|s| {
-                    (
-                        s.left
-                            .node
-                            .as_name()
-                            .unwrap_or(&rustc_span::symbol::Symbol::intern(""))
-                            .to_string(),
-                        s.right
-                            .node
-                            .as_name()
-                            .unwrap_or(&rustc_span::symbol::Symbol::intern(""))
-                            .to_string(),
-                    )
-                })
+                .unique_by(|s| (s.left.as_path(), s.right.as_path()))
                 .collect::<Vec<Segment<'a>>>(),
         )
     }
@@ -0,0 +1,83 @@
+//! The purpose of this module is to provide the error type of the crate.
+
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::result;
+use std::time::Duration;
+
+/// The enumeration `Error` is the failure of a graph's generation.
+#[derive(Debug)]
+pub enum Error {
+    /// A file couldn't be read or written.
+    Io(io::Error),
+    /// A source file couldn't be parsed, with the position (from 1) and message of
+    /// the rustc diagnostic.
+    Parse {
+        file: PathBuf,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The graph couldn't be rendered.
+    Render(String),
+    /// The *Graphviz* binary failed, with its exit code (none when killed by a signal) and
+    /// diagnostics.
+    Graphviz { code: Option<i32>, stderr: String },
+    /// The *Graphviz* binary was killed after the timeout, with its diagnostics until then.
+    Timeout { timeout: Duration, stderr: String },
+    /// The *git* binary failed, with its exit code (none when killed by a signal) and
+    /// diagnostics.
+    Git { code: Option<i32>, stderr: String },
+    /// The configuration or a manifest is invalid.
+    Config(String),
+    /// A model couldn't be exported or imported.
+    Model(String),
+}
+
+/// The type `Result` is the result of a graph's generation.
+pub type Result<T> = result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Error::Io(ref e) => write!(f, "{}", e),
+            &Error::Parse {
+                ref file,
+                line,
+                column,
+                ref message,
+            } => write!(f, "{}:{}:{}: {}", file.display(), line, column, message),
+            &Error::Render(ref message) => write!(f, "render: {}", message),
+            &Error::Graphviz {
+                code: Some(code),
+                ref stderr,
+            } => write!(f, "graphviz: exit code {}: {}", code, stderr),
+            &Error::Graphviz { code: None, ref stderr } => write!(f, "graphviz: killed by a signal: {}", stderr),
+            &Error::Timeout { timeout, ref stderr } => write!(f, "graphviz: timed out after {:?}: {}", timeout, stderr),
+            &Error::Git {
+                code: Some(code),
+                ref stderr,
+            } => write!(f, "git: exit code {}: {}", code, stderr),
+            &Error::Git { code: None, ref stderr } => write!(f, "git: killed by a signal: {}", stderr),
+            &Error::Config(ref message) => write!(f, "config: {}", message),
+            &Error::Model(ref message) => write!(f, "model: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            &Error::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
@@ -0,0 +1,53 @@
+//! The purpose of this module is to read the sources of a revision from a local *git*
+//! repository, to be parsed like a directory.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use crate::{Error, Result};
+
+/// The function `git` returns the standard output of *git* run with `args` in the
+/// repository `repo`.
+fn git(repo: &Path, args: &[&str]) -> Result<Vec<u8>> {
+    let output: Output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .map_err(|e| io::Error::new(e.kind(), format!("git: {} (is git installed?)", e)))?;
+
+    match output.status.success() {
+        true => Ok(output.stdout),
+        false => Err(Error::Git {
+            code: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }),
+    }
+}
+
+/// The function `export` writes the Rust files of the directory `path` (from the root of
+/// the repository `repo`, like `src`) at the revision `revision` below `dest`, and returns
+/// the directory where `path` was written.
+pub fn export<P: AsRef<Path>>(repo: P, revision: &str, path: P, dest: P) -> Result<PathBuf> {
+    let path: String = path.as_ref().to_string_lossy().replace("\\", "/");
+    let files: Vec<u8> = git(
+        repo.as_ref(),
+        &["ls-tree", "-r", "-z", "--full-tree", "--name-only", revision, "--", &path],
+    )?;
+
+    for file in files
+        .split(|&byte| byte == 0)
+        .map(String::from_utf8_lossy)
+        .filter(|file| file.ends_with(".rs"))
+    {
+        let target: PathBuf = dest.as_ref().join(file.as_ref());
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, git(repo.as_ref(), &["show", &format!("{}:{}", revision, file)])?)?;
+    }
+    Ok(dest.as_ref().join(path))
+}
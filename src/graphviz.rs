@@ -0,0 +1,267 @@
+//! The purpose of this module is to run the *Graphviz* binary on a graph: the pipes are
+//! streamed by threads of their own and its failures are reported with its diagnostics.
+
+use std::io::{self, ErrorKind, Read, Write};
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::path::PathBuf;
+use std::process::{Child, ChildStderr, ChildStdout, Command, ExitStatus, Stdio};
+use std::str::FromStr;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::{Error, Result};
+
+/// The interval between two checks of a running binary with a timeout.
+const POLL: Duration = Duration::from_millis(10);
+
+/// The enumeration `Output` is the format written by *Graphviz* (`-T`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Output {
+    Svg,
+    Png,
+    Pdf,
+    /// Client-side image map of the nodes' links.
+    Cmapx,
+    /// JSON of the graph with its layout.
+    Json,
+}
+
+impl Output {
+    /// The method `as_str` returns the name of the format for `-T`, also the extension of
+    /// its files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            &Output::Svg => "svg",
+            &Output::Png => "png",
+            &Output::Pdf => "pdf",
+            &Output::Cmapx => "cmapx",
+            &Output::Json => "json",
+        }
+    }
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Output::Svg
+    }
+}
+
+impl FromStr for Output {
+    type Err = Error;
+
+    fn from_str(output: &str) -> Result<Output> {
+        match output {
+            "svg" => Ok(Output::Svg),
+            "png" => Ok(Output::Png),
+            "pdf" => Ok(Output::Pdf),
+            "cmapx" => Ok(Output::Cmapx),
+            "json" => Ok(Output::Json),
+            output => Err(Error::Config(format!("unknown graphviz output {}", output))),
+        }
+    }
+}
+
+/// The enumeration `Engine` is the layout of *Graphviz* (`-K`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Engine {
+    /// Hierarchical layers.
+    Dot,
+    /// Force-directed placement.
+    Fdp,
+    /// Force-directed placement of large graphs.
+    Sfdp,
+    /// Spring model.
+    Neato,
+}
+
+impl Engine {
+    /// The method `as_str` returns the name of the layout for `-K`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            &Engine::Dot => "dot",
+            &Engine::Fdp => "fdp",
+            &Engine::Sfdp => "sfdp",
+            &Engine::Neato => "neato",
+        }
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Engine::Dot
+    }
+}
+
+impl FromStr for Engine {
+    type Err = Error;
+
+    fn from_str(engine: &str) -> Result<Engine> {
+        match engine {
+            "dot" => Ok(Engine::Dot),
+            "fdp" => Ok(Engine::Fdp),
+            "sfdp" => Ok(Engine::Sfdp),
+            "neato" => Ok(Engine::Neato),
+            engine => Err(Error::Config(format!("unknown graphviz engine {}", engine))),
+        }
+    }
+}
+
+/// The structure `Graphviz` is the way the graphs are given to the *Graphviz* binary.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// use std::time::Duration;
+///
+/// use rust2uml::graphviz::{Engine, Graphviz, Output};
+///
+/// fn main() {
+///     let mut graphviz = Graphviz::default();
+///
+///     graphviz.engine = Engine::Fdp;
+///     graphviz.timeout = Some(Duration::from_secs(30));
+///     let _ = graphviz.render(b"digraph ml { a -> b }", Output::Png);
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Graphviz {
+    /// Path or name (looked up in `PATH`) of the binary.
+    pub binary: PathBuf,
+    pub engine: Engine,
+    /// Format of the files written next to the graphs (`src2both`, ...).
+    pub output: Output,
+    /// Delay after which the binary is killed, none by default.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Graphviz {
+    fn default() -> Self {
+        Graphviz {
+            binary: PathBuf::from("dot"),
+            engine: Engine::default(),
+            output: Output::default(),
+            timeout: None,
+        }
+    }
+}
+
+/// The function `join` returns the result of the thread `handle` of a pipe.
+fn join<T>(handle: JoinHandle<io::Result<T>>) -> Result<T> {
+    handle
+        .join()
+        .map_err(|_| Error::Render("graphviz: a pipe's thread panicked".to_string()))?
+        .map_err(Error::from)
+}
+
+/// The function `drain` reads a pipe to its end from a thread of its own.
+fn drain<R: Read + Send + 'static>(mut pipe: R) -> JoinHandle<io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut buf: Vec<u8> = Vec::new();
+
+        pipe.read_to_end(&mut buf).map(|_| buf)
+    })
+}
+
+/// The function `kill` kills `child` with the processes it started, which could keep its
+/// pipes open.
+fn kill(child: &mut Child) {
+    // The binary leads a process group of its own (see `Graphviz::render`).
+    #[cfg(unix)]
+    let _ = Command::new("kill")
+        .args(["-KILL", "--"])
+        .arg(format!("-{}", child.id()))
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+impl Graphviz {
+    /// The method `wait` returns the exit status of `child`, or none when it's killed after
+    /// the timeout.
+    fn wait(&self, child: &mut Child) -> Result<Option<ExitStatus>> {
+        let start: Instant = Instant::now();
+
+        loop {
+            let status: io::Result<Option<ExitStatus>> = match self.timeout {
+                Some(_) => child.try_wait(),
+                None => child.wait().map(Some),
+            };
+
+            match (status, self.timeout) {
+                (Ok(Some(status)), _) => return Ok(Some(status)),
+                (Ok(None), Some(timeout)) if start.elapsed() >= timeout => {
+                    kill(child);
+                    return Ok(None);
+                }
+                (Ok(None), _) => thread::sleep(POLL),
+                // A binary left running would keep the pipes' threads waiting.
+                (Err(e), _) => {
+                    kill(child);
+                    return Err(Error::from(e));
+                }
+            }
+        }
+    }
+
+    /// The method `render` returns the graph `dot` laid out by the engine and written in
+    /// `output` by the binary.
+    pub fn render(&self, dot: &[u8], output: Output) -> Result<Vec<u8>> {
+        let mut command: Command = Command::new(&self.binary);
+
+        command
+            .arg(format!("-K{}", self.engine.as_str()))
+            .arg(format!("-T{}", output.as_str()))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        // A process group, to be killed with the processes it starts after the timeout.
+        #[cfg(unix)]
+        command.process_group(0);
+        let mut child: Child = command
+            .spawn()
+            .map_err(|e| {
+                Error::Render(format!(
+                    "{}: {} (is graphviz installed? see Backend::Builtin)",
+                    self.binary.display(),
+                    e
+                ))
+            })?;
+        // Each pipe has its thread, so the binary never waits for a full pipe to be read
+        // while the graph is still being written.
+        let stdin: Option<JoinHandle<io::Result<()>>> = child.stdin.take().map(|mut stdin| {
+            let dot: Vec<u8> = dot.to_vec();
+
+            // Dropping `stdin` at the end of the thread closes the pipe. A binary which stops
+            // reading breaks the pipe, its exit status tells whether it failed.
+            thread::spawn(move || match stdin.write_all(&dot) {
+                Err(ref e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+                written => written,
+            })
+        });
+        let stdout: Option<JoinHandle<io::Result<Vec<u8>>>> = child.stdout.take().map(drain::<ChildStdout>);
+        let stderr: Option<JoinHandle<io::Result<Vec<u8>>>> = child.stderr.take().map(drain::<ChildStderr>);
+        // The pipes are closed once the binary exits or is killed, so the threads end.
+        let status: Result<Option<ExitStatus>> = self.wait(&mut child);
+        let written: Option<Result<()>> = stdin.map(join);
+        let content: Result<Vec<u8>> = stdout.map(join).unwrap_or_else(|| Ok(Vec::new()));
+        let diagnostics: Vec<u8> = stderr.map(join).unwrap_or_else(|| Ok(Vec::new()))?;
+        let stderr: String = String::from_utf8_lossy(&diagnostics).trim().to_string();
+
+        match (status?, written) {
+            (None, _) => Err(Error::Timeout {
+                timeout: self.timeout.unwrap_or_default(),
+                stderr: stderr,
+            }),
+            (Some(status), _) if !status.success() => Err(Error::Graphviz {
+                code: status.code(),
+                stderr: stderr,
+            }),
+            (Some(_), Some(Err(e))) => Err(e),
+            (Some(_), _) => content,
+        }
+    }
+}
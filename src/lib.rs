@@ -22,6 +22,7 @@ extern crate walkdir;
 
 extern crate rustc_ast;
 extern crate rustc_ast_pretty;
+extern crate rustc_data_structures;
 extern crate rustc_error_codes;
 extern crate rustc_errors;
 extern crate rustc_hash;
@@ -35,34 +36,54 @@ extern crate thin_vec;
 extern crate rustc_driver;
 
 pub mod core;
+pub mod error;
+pub mod git;
+pub mod graphviz;
+pub mod model;
 pub mod module;
 pub mod prelude;
+pub mod renderer;
 
+use std::cell::RefCell;
+use std::env;
 use std::ffi::OsStr;
-use std::fs::{self, File};
-use std::io::{self, Read, Write};
-use std::path::Path;
-use std::process::{Command, Stdio};
-use std::rc::Rc;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
 
-use rustc_ast::{ast, ptr};
+use rustc_ast::ast;
+use rustc_data_structures::sync::Lrc;
+use rustc_errors::emitter::Emitter;
+use rustc_errors::translation::{to_fluent_args, Translate};
+use rustc_errors::{fallback_fluent_bundle, Diag, DiagCtxt, DiagInner, FluentBundle, LazyFallbackBundle};
 use rustc_session::parse::ParseSess;
-//use rustc_span::source_map::{FilePathMapping, SourceMap};
+use rustc_span::source_map::{FilePathMapping, SourceMap};
 //use rustc_span::FileName;
 use rustc_span::RealFileName;
 
-use crate::core::ListItem;
-use module::path::ModulePath;
+pub use crate::error::{Error, Result};
+pub use crate::model::diff::{Change, Diff};
+pub use crate::model::filter::{Filter, Rule};
+pub use crate::model::focus::{Direction, Focus};
+pub use crate::model::Model;
+pub use crate::renderer::{Backend, Format, Renderer};
+use module::workspace::Target;
 use module::Module;
-use once_cell::sync::OnceCell;
 use walkdir::WalkDir;
 
 /// The default name of *graph/dot* file.
 pub const DEFAULT_NAME_DOT: &'static str = "ml.dot";
 /// The default name of *image/svg* file.
-pub const DEFAULT_NAME_PNG: &'static str = "ml.svg";
+pub const DEFAULT_NAME_SVG: &'static str = "ml.svg";
+/// The default name of *image/png* file.
+pub const DEFAULT_NAME_PNG: &'static str = "ml.png";
+/// The default name of *model/json* file.
+pub const DEFAULT_NAME_JSON: &'static str = "ml.json";
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Config {
     pub include_methods: bool,
     pub include_fields: bool,
@@ -80,22 +101,51 @@ pub struct Config {
     pub trait_implem_bgcolor: String,
     pub font_name: String,
     pub src_url_mask: String,
+    /// Depth of the clusters of modules of the dot graphs below the crate, whose deeper
+    /// submodules are merged into them; none for a flat graph.
+    pub cluster_depth: Option<usize>,
+    /// Types to draw with their neighbours in the diagrams of every format, instead of every type.
+    pub focus: Option<Focus>,
+    /// Rules on the module, name and kind of the types left out of the diagrams, with
+    /// their relations.
+    pub filter: Filter,
+    /// Only what the downstream crates see: the `pub` types of the publicly reachable modules
+    /// or re-exported by their `pub use`, with their public members.
+    ///
+    /// The reachable modules are known from the crate root (`lib.rs` or `main.rs`): when a
+    /// directory without one is walked, every module counts as public.
+    pub public_api: bool,
 }
-static INSTANCE: OnceCell<Config> = OnceCell::new();
+static INSTANCE: RwLock<Option<Config>> = RwLock::new(None);
+/// The count of the revisions read by `parse_revision`, which names their directories.
+static REVISIONS: AtomicUsize = AtomicUsize::new(0);
 
 impl Config {
+    /// The function `set_global` replaces the configuration of the free functions
+    /// (`src2dot`, ...), see `Renderer` to render with several configurations.
     pub fn set_global(config: Self) {
-        INSTANCE.set(config).unwrap();
+        *INSTANCE.write().unwrap_or_else(|e| e.into_inner()) = Some(config);
     }
 
-    pub(crate) fn global() -> &'static Self {
-        INSTANCE.get().expect("config is not initialized")
+    /// The function `global` returns the configuration of the free functions,
+    /// or else the default one.
+    pub fn global() -> Self {
+        INSTANCE
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .unwrap_or_default()
     }
 }
 
+/// The structure `WithConfig` displays an element of the graph with the given
+/// configuration instead of the global one.
+#[derive(Debug)]
+pub struct WithConfig<'c, T: 'c>(pub &'c T, pub &'c Config);
+
 impl Default for Config {
     fn default() -> Self {
-        let current_dir = match std::env::current_dir() {
+        let current_dir = match env::current_dir() {
             Ok(pb) => pb.into_os_string().into_string().unwrap_or_else(|_| "".to_string()),
             Err(_) => "".to_string(),
         };
@@ -118,40 +168,148 @@ impl Default for Config {
             trait_implem_bgcolor: "white".to_string(),
             font_name: "Arial".to_string(),
             src_url_mask,
+            cluster_depth: None,
+            focus: None,
+            filter: Filter::default(),
+            public_api: false,
         }
     }
 }
 
-/// The function `file2crate` returns a syntex module.
-fn file2crate(path: &Path) -> io::Result<ast::Crate> {
+thread_local! {
+    /// The errors from which the parser recovers, with their line, column and message,
+    /// kept by `Recovered` until `file2crate` returns them.
+    static RECOVERED: RefCell<Vec<(usize, usize, String)>> = RefCell::new(Vec::new());
+}
+
+/// The structure `Recovered` is the emitter of the parse sessions, which keeps the errors
+/// from which the parser recovers, to be returned instead of being printed.
+struct Recovered {
+    source_map: Lrc<SourceMap>,
+    fallback_bundle: LazyFallbackBundle,
+}
+
+impl Translate for Recovered {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        None
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for Recovered {
+    fn emit_diagnostic(&mut self, diag: DiagInner) {
+        if diag.is_error() {
+            let (line, column): (usize, usize) = match diag.span.primary_span() {
+                Some(span) => {
+                    let loc: rustc_span::Loc = self.source_map.lookup_char_pos(span.lo());
+
+                    (loc.line, loc.col.0 + 1)
+                }
+                None => (0, 0),
+            };
+            let message: String = self
+                .translate_messages(&diag.messages, &to_fluent_args(diag.args.iter()))
+                .into_owned();
+
+            RECOVERED.with(|recovered| recovered.borrow_mut().push((line, column, message)));
+        }
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.source_map)
+    }
+}
+
+/// The function `parse_session` returns a new ParseSess shared by the files of a generation,
+/// with the rustc's messages which translate the diagnostics.
+fn parse_session() -> ParseSess {
+    let source_map: Lrc<SourceMap> = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+    let emitter: Recovered = Recovered {
+        source_map: source_map.clone(),
+        fallback_bundle: fallback_fluent_bundle(rustc_driver::DEFAULT_LOCALE_RESOURCES.to_vec(), false),
+    };
+
+    ParseSess::with_dcx(DiagCtxt::new(Box::new(emitter)), source_map)
+}
+
+/// The function `file2crate` returns a syntex module, pushing to `diagnostics` the errors
+/// from which the parser recovers.
+fn file2crate(parse_session: &ParseSess, path: &Path, diagnostics: &mut Vec<Error>) -> Result<ast::Crate> {
     // Read the source file content.
     let file_content = fs::read_to_string(path)?;
-    // Create a new ParseSess with an empty configuration.
-    let parse_session = ParseSess::new(Vec::new());
     // Construct a proper filename using the updated FileName API.
     let filename: rustc_span::FileName = rustc_span::FileName::Real(RealFileName::LocalPath(path.to_path_buf()));
+    // The diagnostics are cancelled, to be returned instead of being printed.
+    let diag2error = |diag: Diag<'_>| -> Error {
+        let (line, column): (usize, usize) = match diag.span.primary_span() {
+            Some(span) => {
+                let loc: rustc_span::Loc = parse_session.source_map().lookup_char_pos(span.lo());
+
+                (loc.line, loc.col.0 + 1)
+            }
+            None => (0, 0),
+        };
+        let message: String = diag
+            .messages
+            .iter()
+            .map(|&(ref message, _)| {
+                parse_session
+                    .dcx()
+                    .eagerly_translate_to_string(message.clone(), diag.args.iter())
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        diag.cancel();
+        Error::Parse {
+            file: path.to_path_buf(),
+            line: line,
+            column: column,
+            message: message,
+        }
+    };
     // Create a new parser from the source string.
-    let mut parser = rustc_parse::new_parser_from_source_str(
-        &parse_session,
-        filename,
-        file_content,
-        
-    ).unwrap();
-    // Parse the crate module using the updated parser API.
-    let krate = parser.parse_crate_mod().unwrap();
-    Ok(krate)
-}
-
-/// The function `items2chars` returns a graph formated for *Graphiz/Dot*.
-fn items2chars<'a>(modules: Vec<Module>) -> io::Result<Vec<u8>> {
-    let mut f: Vec<u8> = Vec::new();
-    let itt: Vec<(ptr::P<ast::Item>, Rc<ModulePath>)> = modules
-        .into_iter()
-        .flat_map(|s: Module| s.into_iter())
-        .collect::<Vec<(ptr::P<ast::Item>, Rc<ModulePath>)>>();
-    let it: ListItem = ListItem::from(itt.as_slice().into_iter().peekable());
+    let parse: Result<ast::Crate> = rustc_parse::new_parser_from_source_str(parse_session, filename, file_content)
+        .map_err(|diags: Vec<Diag<'_>>| {
+            let mut errors = diags.into_iter().map(&diag2error);
+            let error: Option<Error> = errors.next();
 
-    dot::render(&it, &mut f).and_then(|()| Ok(f))
+            errors.for_each(drop);
+            error.unwrap_or_else(|| Error::Parse {
+                file: path.to_path_buf(),
+                line: 0,
+                column: 0,
+                message: "invalid source".to_string(),
+            })
+        })
+        // Parse the crate module using the updated parser API.
+        .and_then(|mut parser| parser.parse_crate_mod().map_err(diag2error));
+
+    RECOVERED.with(|recovered| {
+        diagnostics.extend(
+            recovered
+                .borrow_mut()
+                .drain(..)
+                .map(|(line, column, message)| Error::Parse {
+                    file: path.to_path_buf(),
+                    line: line,
+                    column: column,
+                    message: message,
+                }),
+        )
+    });
+    parse
+}
+
+/// The function `strict` returns the output of a generation, or else its first diagnostic.
+fn strict<T>((output, diagnostics): (T, Vec<Error>)) -> Result<T> {
+    match diagnostics.into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(output),
+    }
 }
 
 /// The function `rs2dot` returns graphed file module.
@@ -164,15 +322,82 @@ fn items2chars<'a>(modules: Vec<Module>) -> io::Result<Vec<u8>> {
 ///     let _ = rust2uml::rs2dot("src/lib.rs");
 /// }
 /// ```
-pub fn rs2dot<'a, P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
-    rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
-        file2crate(path.as_ref()).and_then(|parse: ast::Crate| {
-            items2chars(vec![Module::from((
-                Vec::from(parse.items.clone()),
-                path.as_ref().to_path_buf(),
-            ))])
-        })
-    })
+pub fn rs2dot<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().rs2dot(path)
+}
+
+/// The function `crate2dot_partial` returns graphed crate from its root file, skipping
+/// the files which fail to be parsed, with their diagnostics.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     if let Ok((_, diagnostics)) = rust2uml::crate2dot_partial("src/lib.rs") {
+///         diagnostics.iter().for_each(|e| eprintln!("{}", e));
+///     }
+/// }
+/// ```
+pub fn crate2dot_partial<P: AsRef<Path>>(path: P) -> Result<(Vec<u8>, Vec<Error>)> {
+    Renderer::default().crate2dot_partial(path)
+}
+
+/// The function `crate2dot` returns graphed crate from its root file (`lib.rs`/`main.rs`),
+/// following the `mod` declarations like rustc does.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::crate2dot("src/lib.rs");
+/// }
+/// ```
+pub fn crate2dot<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().crate2dot(path)
+}
+
+/// The function `src2modules` returns the modules of a source directory: the module tree of
+/// its crate root when there is a `lib.rs`/`main.rs`, or else every `.rs` file below it.
+fn src2modules(parse_session: &ParseSess, path: &Path, diagnostics: &mut Vec<Error>) -> Vec<Module> {
+    match module::tree::root_of(path) {
+        Some(root) => module::tree::resolve(parse_session, root, diagnostics),
+        None => {
+            let mut modules: Vec<Module> = Vec::new();
+
+            for entry in WalkDir::new(path) {
+                match entry {
+                    Ok(ref entry) if entry.file_type().is_file() && entry.path().extension() == Some(OsStr::new("rs")) => {
+                        match file2crate(parse_session, entry.path(), diagnostics) {
+                            Ok(parse) => modules.push(Module::from((Vec::from(parse.items.clone()), entry.path().to_path_buf()))),
+                            Err(e) => diagnostics.push(e),
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => diagnostics.push(Error::from(io::Error::from(e))),
+                }
+            }
+            modules
+        }
+    }
+}
+
+/// The function `src2dot_partial` returns graphed repository of modules, skipping
+/// the files which fail to be parsed, with their diagnostics.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     if let Ok((_, diagnostics)) = rust2uml::src2dot_partial("src") {
+///         diagnostics.iter().for_each(|e| eprintln!("{}", e));
+///     }
+/// }
+/// ```
+pub fn src2dot_partial<P: AsRef<Path>>(path: P) -> Result<(Vec<u8>, Vec<Error>)> {
+    Renderer::default().src2dot_partial(path)
 }
 
 /// The function `src2dot` returns graphed repository of modules.
@@ -185,76 +410,488 @@ pub fn rs2dot<'a, P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
 ///     let _ = rust2uml::src2dot("src");
 /// }
 /// ```
-pub fn src2dot<'a, P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
-    rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
-        items2chars(
-            WalkDir::new(path)
+pub fn src2dot<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2dot(path)
+}
+
+/// The function `workspace2modules` returns the modules of every crate of a package or
+/// workspace, tagged with their crate.
+fn workspace2modules(
+    parse_session: &ParseSess,
+    manifest: &Path,
+    diagnostics: &mut Vec<Error>,
+) -> Result<Vec<(Target, Vec<Module>)>> {
+    Ok(module::workspace::targets(manifest)?
+        .into_iter()
+        .map(|target: Target| {
+            let krate: String = target.as_krate();
+            let modules: Vec<Module> = module::tree::resolve(parse_session, &target.root, diagnostics)
                 .into_iter()
-                .filter_map(|entry: Result<walkdir::DirEntry, _>| entry.ok())
-                .filter(|entry| entry.file_type().is_file())
-                .filter_map(|entry: walkdir::DirEntry| {
-                    let path: &Path = entry.path();
-
-                    if path.extension() == Some(OsStr::new("rs")) {
-                        file2crate(path)
-                            .ok()
-                            .and_then(|parse| Some(Module::from((Vec::from(parse.items.clone()), path.to_path_buf()))))
-                    } else {
-                        None
-                    }
+                .map(|mut module: Module| {
+                    module.path.krate = krate.clone();
+                    module
                 })
-                .collect::<Vec<Module>>(),
-        )
-    })
+                .collect::<Vec<Module>>();
+
+            (target, modules)
+        })
+        .collect::<Vec<(Target, Vec<Module>)>>())
+}
+
+/// The function `workspace2dot_partial` returns one graph of every crate of a package or
+/// workspace, skipping the files which fail to be parsed, with their diagnostics.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     if let Ok((_, diagnostics)) = rust2uml::workspace2dot_partial("Cargo.toml") {
+///         diagnostics.iter().for_each(|e| eprintln!("{}", e));
+///     }
+/// }
+/// ```
+pub fn workspace2dot_partial<P: AsRef<Path>>(manifest: P) -> Result<(Vec<u8>, Vec<Error>)> {
+    Renderer::default().workspace2dot_partial(manifest)
+}
+
+/// The function `workspace2dot` returns one graph of every crate (lib, bins and examples)
+/// of a package or workspace from its `Cargo.toml`.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::workspace2dot("Cargo.toml");
+/// }
+/// ```
+pub fn workspace2dot<P: AsRef<Path>>(manifest: P) -> Result<Vec<u8>> {
+    Renderer::default().workspace2dot(manifest)
+}
+
+/// The function `workspace2dots` returns a graph by crate of a package or workspace
+/// from its `Cargo.toml`, with the crate's tag.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::workspace2dots("Cargo.toml");
+/// }
+/// ```
+pub fn workspace2dots<P: AsRef<Path>>(manifest: P) -> Result<Vec<(String, Vec<u8>)>> {
+    Renderer::default().workspace2dots(manifest)
 }
 
-/// The function `content2svg` returns structured vector graphics content of modules.
-fn content2svg(buf: Vec<u8>) -> io::Result<Vec<u8>> {
-    Command::new("dot")
-        .arg("-Tsvg")
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .spawn()
-        .and_then(|child| {
-            let mut ret = vec![];
-            child.stdin.unwrap().write_all(buf.as_slice()).unwrap();
-            child.stdout.unwrap().read_to_end(&mut ret).unwrap();
-            Ok(ret)
+/// The function `parse_file` returns the model of a file module.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::parse_file("src/lib.rs");
+/// }
+/// ```
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<Model> {
+    rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
+        let parse_session: ParseSess = parse_session();
+        let mut diagnostics: Vec<Error> = Vec::new();
+        let parse: Result<ast::Crate> = file2crate(&parse_session, path.as_ref(), &mut diagnostics);
+
+        parse.and_then(|parse: ast::Crate| strict((parse, diagnostics))).map(|parse: ast::Crate| {
+            let module: Module = Module::from((Vec::from(parse.items.clone()), path.as_ref().to_path_buf()));
+
+            Model::from((vec![module], parse_session.source_map()))
         })
+    })
 }
 
-/// The function `rs2svg` returns structured vector graphics file modules.
+/// The function `parse_crate` returns the model of a crate from its root file
+/// (`lib.rs`/`main.rs`), following the `mod` declarations like rustc does.
 ///
 /// # Examples
 /// ```
 /// extern crate rust2uml;
 ///
 /// fn main() {
-///     let _ = rust2uml::rs2svg("src/lib.rs");
+///     if let Ok(model) = rust2uml::parse_crate("src/lib.rs") {
+///         model.nodes.iter().for_each(|node| println!("{}", node.path.join("::")));
+///     }
 /// }
 /// ```
-pub fn rs2svg<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+pub fn parse_crate<P: AsRef<Path>>(path: P) -> Result<Model> {
     rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
-        rs2dot(path).and_then(|buf| content2svg(buf))
+        let parse_session: ParseSess = parse_session();
+        let mut diagnostics: Vec<Error> = Vec::new();
+        let modules: Vec<Module> = module::tree::resolve(&parse_session, path, &mut diagnostics);
+
+        strict((modules, diagnostics)).map(|modules: Vec<Module>| Model::from((modules, parse_session.source_map())))
     })
 }
 
-/// The function `src2svg` returns structured vector graphics repository of modules.
+/// The function `parse_dir` returns the model of a repository of modules.
 ///
 /// # Examples
 /// ```
 /// extern crate rust2uml;
 ///
 /// fn main() {
-///     let _ = rust2uml::src2svg("src");
+///     let _ = rust2uml::parse_dir("src");
 /// }
 /// ```
-pub fn src2svg<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+pub fn parse_dir<P: AsRef<Path>>(path: P) -> Result<Model> {
     rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
-        src2dot(path).and_then(|buf| content2svg(buf))
+        let parse_session: ParseSess = parse_session();
+        let mut diagnostics: Vec<Error> = Vec::new();
+        let modules: Vec<Module> = src2modules(&parse_session, path.as_ref(), &mut diagnostics);
+
+        strict((modules, diagnostics)).map(|modules: Vec<Module>| Model::from((modules, parse_session.source_map())))
     })
 }
 
+/// The function `parse_workspace` returns the model of every crate (lib, bins and examples)
+/// of a package or workspace from its `Cargo.toml`.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::parse_workspace("Cargo.toml");
+/// }
+/// ```
+pub fn parse_workspace<P: AsRef<Path>>(manifest: P) -> Result<Model> {
+    rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
+        let parse_session: ParseSess = parse_session();
+        let mut diagnostics: Vec<Error> = Vec::new();
+        let crates: Vec<(Target, Vec<Module>)> = workspace2modules(&parse_session, manifest.as_ref(), &mut diagnostics)?;
+        let modules: Vec<Module> = crates.into_iter().flat_map(|(_, modules)| modules).collect::<Vec<Module>>();
+
+        strict((modules, diagnostics)).map(|modules: Vec<Module>| Model::from((modules, parse_session.source_map())))
+    })
+}
+
+/// The function `parse_revision` returns the model of the repository of modules `path` (from
+/// the root of the *git* repository `repo`, like `src`) at the revision `revision`, read into
+/// a temporary directory.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::parse_revision(".", "HEAD", "src");
+/// }
+/// ```
+pub fn parse_revision<P: AsRef<Path>>(repo: P, revision: &str, path: P) -> Result<Model> {
+    // a directory by call, as parallel calls of the process can read the same revision.
+    let dest: PathBuf = env::temp_dir().join(format!(
+        "ml-{}-{}-{}",
+        process::id(),
+        REVISIONS.fetch_add(1, Ordering::Relaxed),
+        revision.replace(|at: char| !at.is_ascii_alphanumeric(), "_")
+    ));
+    let _ = fs::remove_dir_all(&dest);
+    let model: Result<Model> = git::export(repo.as_ref(), revision, path.as_ref(), dest.as_path())
+        .and_then(parse_dir)
+        .map(|model: Model| relocate(model, dest.as_path()));
+
+    let _ = fs::remove_dir_all(&dest);
+    model
+}
+
+/// The function `relocate` returns the model `model` read from the temporary directory `dest`
+/// with the source files at their path from the root of the repository, as the directory is
+/// removed.
+fn relocate(mut model: Model, dest: &Path) -> Model {
+    let relative = |file: &mut PathBuf| {
+        if let Ok(rest) = file.strip_prefix(dest) {
+            *file = rest.to_path_buf();
+        }
+    };
+
+    for package in model.packages.iter_mut() {
+        relative(&mut package.file);
+    }
+    for node in model.nodes.iter_mut() {
+        if let Some(ref mut span) = node.span {
+            relative(&mut span.file);
+        }
+    }
+    model
+}
+
+/// The function `model2dot` returns the model `model` formated for *Graphiz/Dot*.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     if let Ok(model) = rust2uml::parse_dir("src") {
+///         let _ = rust2uml::model2dot(&model);
+///     }
+/// }
+/// ```
+pub fn model2dot(model: &Model) -> Result<Vec<u8>> {
+    Renderer::default().model2dot(model)
+}
+
+/// The function `json2dot` returns graphed model from its JSON file (see `Model::to_json`).
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::json2dot("target/doc/ml.json");
+/// }
+/// ```
+pub fn json2dot<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().json2dot(path)
+}
+
+/// The function `rs2plantuml` returns the *PlantUML* class diagram of a file module.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::rs2plantuml("src/lib.rs");
+/// }
+/// ```
+pub fn rs2plantuml<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().rs2plantuml(path)
+}
+
+/// The function `src2plantuml` returns the *PlantUML* class diagram of a repository of modules.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2plantuml("src");
+/// }
+/// ```
+pub fn src2plantuml<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2plantuml(path)
+}
+
+/// The function `rs2mermaid` returns the *Mermaid* `classDiagram` of a file module.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::rs2mermaid("src/lib.rs");
+/// }
+/// ```
+pub fn rs2mermaid<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().rs2mermaid(path)
+}
+
+/// The function `src2mermaid` returns the *Mermaid* `classDiagram` of a repository of modules.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2mermaid("src");
+/// }
+/// ```
+pub fn src2mermaid<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2mermaid(path)
+}
+
+/// The function `src2xmi` returns the *XMI 2.5* document of a repository of modules, to be
+/// imported by the *UML* modelling tools.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2xmi("src");
+/// }
+/// ```
+pub fn src2xmi<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2xmi(path)
+}
+
+/// The function `src2drawio` returns the *draw.io* (diagrams.net) file of a repository of
+/// modules, laid out without graphviz.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2drawio("src");
+/// }
+/// ```
+pub fn src2drawio<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2drawio(path)
+}
+
+/// The function `src2d2` returns the *D2* diagram of a repository of modules.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2d2("src");
+/// }
+/// ```
+pub fn src2d2<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2d2(path)
+}
+
+/// The function `src2graphml` returns the *GraphML* document of the type graph of a
+/// repository of modules.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2graphml("src");
+/// }
+/// ```
+pub fn src2graphml<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2graphml(path)
+}
+
+/// The function `src2structurizr` returns the *Structurizr DSL* workspace of a repository
+/// of modules: the crates are containers and the top-level modules components, with the
+/// types and their relations as properties when `code` is set.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2structurizr("src", false);
+/// }
+/// ```
+pub fn src2structurizr<P: AsRef<Path>>(path: P, code: bool) -> Result<Vec<u8>> {
+    Renderer::default().src2structurizr(path, code)
+}
+
+/// The function `rs2svg` returns structured vector graphics file modules.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::rs2svg("src/lib.rs");
+/// }
+/// ```
+pub fn rs2svg<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().rs2svg(path)
+}
+
+/// The function `src2svg` returns structured vector graphics repository of modules.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2svg("src");
+/// }
+/// ```
+pub fn src2svg<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2svg(path)
+}
+
+/// The function `src2html` returns one HTML page of a repository of modules, without
+/// external resource: its structured vector graphics can be panned, zoomed and searched,
+/// and a click on a type highlights its relations and lists its members.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2html("src");
+/// }
+/// ```
+pub fn src2html<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2html(path)
+}
+
+/// The function `src2text` returns the types of a repository of modules as plain text:
+/// box-drawing classes and their relations, module by module.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2text("src");
+/// }
+/// ```
+pub fn src2text<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2text(path)
+}
+
+/// The function `src2package` returns the package diagram of a repository of modules for
+/// *Graphviz/Dot*: one node by module nested in the clusters of its parents, and the
+/// dependencies between modules (relations of their types and `use` declarations) weighted
+/// by their number of references.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2package("src");
+/// }
+/// ```
+pub fn src2package<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    Renderer::default().src2package(path)
+}
+
+/// The function `src2diff` returns the comparison of the repositories of modules `old` and
+/// `new`: the added, removed and changed types, members and relations (see `Diff::to_json`
+/// and `Renderer::diff2dot`).
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::src2diff("../old/src", "src");
+/// }
+/// ```
+pub fn src2diff<P: AsRef<Path>>(old: P, new: P) -> Result<Diff> {
+    Renderer::default().src2diff(old, new)
+}
+
+/// The function `git2diff` returns the comparison of the repository of modules `path` (from
+/// the root of the *git* repository `repo`) at the revisions `old` and `new`.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let _ = rust2uml::git2diff(".", "src", "HEAD~1", "HEAD");
+/// }
+/// ```
+pub fn git2diff<P: AsRef<Path>>(repo: P, path: P, old: &str, new: &str) -> Result<Diff> {
+    Renderer::default().git2diff(repo, path, old, new)
+}
+
 /// The function `src2both` creates two files formated like a graph/dot and a structured vector graphics.
 ///
 /// # Examples
@@ -267,20 +904,21 @@ pub fn src2svg<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
 ///    let _ = rust2uml::src2both("src", dest.replace("-", "_").as_str());
 /// }
 /// ```
-pub fn src2both<P: AsRef<Path>>(src: P, dest: P) -> io::Result<()> {
-    fn worker<P: AsRef<Path>>(src: P, dest: P) -> io::Result<()> {
-        let _ = fs::create_dir_all(dest.as_ref())?;
-        let mut file_dot = File::create(dest.as_ref().join(DEFAULT_NAME_DOT))?;
-        let mut file_svg = File::create(dest.as_ref().join(DEFAULT_NAME_PNG))?;
-        let content_dot: Vec<u8> = src2dot(src)?;
-        let _ = file_dot.write_all(content_dot.as_slice())?;
-        let content_svg: Vec<u8> = content2svg(content_dot)?;
-        let _ = file_svg.write_all(content_svg.as_slice())?;
-        Ok(())
-    }
-    rustc_span::create_session_if_not_set_then(
-        rustc_span::edition::LATEST_STABLE_EDITION,
-        |_sg| worker(src, dest),
-    )?;
-    Ok(())
+pub fn src2both<P: AsRef<Path>>(src: P, dest: P) -> Result<()> {
+    Renderer::default().src2both(src, dest)
+}
+
+/// The function `workspace2both` creates a graph/dot and a structured vector graphics file
+/// of a package or workspace, combined or else one pair by crate named after the crate.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///    let _ = rust2uml::workspace2both("Cargo.toml", "target/doc/workspace", true);
+/// }
+/// ```
+pub fn workspace2both<P: AsRef<Path>>(manifest: P, dest: P, per_crate: bool) -> Result<()> {
+    Renderer::default().workspace2both(manifest, dest, per_crate)
 }
@@ -0,0 +1,152 @@
+//! The purpose of this module is to render a model in the *D2* diagram language, the modules
+//! being containers of `shape: class` objects.
+
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::Config;
+
+use super::{Edge, Model, Node};
+
+/// The function `quote` returns `text` as a quoted *D2* string.
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The function `key` returns the key of the object of path `path` from the root, the
+/// unknown crate's tag being skipped.
+fn key(path: &[String]) -> String {
+    path.iter()
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| quote(segment))
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+/// The function `object` writes the `shape: class` object of `node`.
+fn object<W: Write>(w: &mut W, node: &Node, config: &Config, indent: &str) -> io::Result<()> {
+    let name: String = match node.params.is_empty() {
+        true => node.name().to_string(),
+        false => format!("{}<{}>", node.name(), node.params.join(", ")),
+    };
+
+    writeln!(w, "{}{}: {} {{", indent, quote(node.name()), quote(&name))?;
+    writeln!(w, "{}  shape: class", indent)?;
+    if config.include_fields {
+        for (at, field) in node.fields.iter().enumerate() {
+            writeln!(
+                w,
+                "{}  {}: {}",
+                indent,
+                quote(&format!(
+                    "{}{}",
+                    field.vis.marker(),
+                    field.name.clone().unwrap_or_else(|| at.to_string())
+                )),
+                quote(&field.ty)
+            )?;
+        }
+        for variant in node.variants.iter() {
+            writeln!(
+                w,
+                "{}  {}: {}",
+                indent,
+                quote(&format!("+{}", variant.name)),
+                quote(&variant.fields.join(", "))
+            )?;
+        }
+    }
+    if config.include_methods {
+        for function in node.items.iter().chain(node.methods.iter()) {
+            writeln!(
+                w,
+                "{}  {}: {}",
+                indent,
+                quote(&function.operation()),
+                quote(function.returns())
+            )?;
+        }
+    }
+    if config.include_implems {
+        for function in node.implems.iter().flat_map(|implem| implem.methods.iter()) {
+            writeln!(
+                w,
+                "{}  {}: {}",
+                indent,
+                quote(&function.operation()),
+                quote(function.returns())
+            )?;
+        }
+    }
+    writeln!(w, "{}}}", indent)
+}
+
+/// The function `container` writes the objects of the module `path` and the containers
+/// of its submodules.
+fn container<W: Write>(w: &mut W, model: &Model, config: &Config, depth: usize, path: &[String]) -> io::Result<()> {
+    let indent: String = "  ".repeat(depth);
+
+    for node in model.types_of(path) {
+        object(w, node, config, &indent)?;
+    }
+    for child in model.submodules(path) {
+        match child.last().map(String::as_str) {
+            // The nodes of an unknown crate have no container of crate.
+            Some("") | None => container(w, model, config, depth, child)?,
+            Some(name) => {
+                writeln!(w, "{}{}: {{", indent, quote(name))?;
+                container(w, model, config, depth + 1, child)?;
+                writeln!(w, "{}}}", indent)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The function `connection` writes the connection of `edge`, whose arrowhead is drawn at
+/// the holder like `Relation::as_style`.
+fn connection<W: Write>(w: &mut W, edge: &Edge) -> io::Result<()> {
+    let (arrow, style): (&str, Vec<&str>) = match (edge.relation, edge.reverse) {
+        (Relation::Association, Relation::Association) | (Relation::None, _) => ("--", vec![]),
+        (Relation::Composition, _) => (
+            "->",
+            vec!["target-arrowhead.shape: diamond", "target-arrowhead.style.filled: true"],
+        ),
+        (Relation::Aggregation, _) => (
+            "->",
+            vec!["target-arrowhead.shape: diamond", "target-arrowhead.style.filled: false"],
+        ),
+        (Relation::Realization, _) => (
+            "->",
+            vec![
+                "target-arrowhead.shape: triangle",
+                "target-arrowhead.style.filled: false",
+                "style.stroke-dash: 3",
+            ],
+        ),
+        (Relation::Dependency, _) => ("->", vec!["target-arrowhead.shape: arrow", "style.stroke-dash: 3"]),
+        (Relation::Association, _) => ("->", vec!["target-arrowhead.shape: arrow"]),
+    };
+
+    write!(w, "{} {} {}", key(&edge.source), arrow, key(&edge.target))?;
+    match style.is_empty() {
+        true => writeln!(w),
+        false => {
+            writeln!(w, ": {{")?;
+            for line in style {
+                writeln!(w, "  {}", line)?;
+            }
+            writeln!(w, "}}")
+        }
+    }
+}
+
+/// The function `render` writes the *D2* diagram of `model` to `w`.
+pub fn render<W: Write>(model: &Model, config: &Config, w: &mut W) -> io::Result<()> {
+    writeln!(w, "direction: down")?;
+    container(w, model, config, 0, &[])?;
+    for edge in model.relations() {
+        connection(w, edge)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,421 @@
+//! The purpose of this module is to compare two models: the types, members and relations
+//! added, removed or changed, written as a machine-readable summary and drawn for
+//! *Graphviz/Dot* in one graph of both models.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::core::item::relation::Relation;
+use crate::dot::{escape_html, Arrow, Edges, GraphWalk, Id, LabelText, Labeller, Nodes, Style};
+use crate::{Config, Error, Result, WithConfig};
+
+use super::dot::compartments;
+use super::{Edge, Function, Kind, Model, Node};
+
+/// The enumeration `Change` is the way an element differs from a model to the other.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Change {
+    /// Only in the new model.
+    Added,
+    /// Only in the old model.
+    Removed,
+    /// In both models, with differences.
+    Changed,
+}
+
+impl Change {
+    /// The method `as_color` returns the color of the change in the diagrams: green, red and
+    /// a yellow which is readable on white.
+    pub fn as_color(&self) -> &'static str {
+        match self {
+            &Change::Added => "green",
+            &Change::Removed => "red",
+            &Change::Changed => "gold",
+        }
+    }
+}
+
+/// The structure `TypeChange` is a type which differs, with the members (signatures of its
+/// fields, variants, items, methods and implementations) a changed type gained or lost.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct TypeChange {
+    pub path: Vec<String>,
+    pub kind: Kind,
+    pub change: Change,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+}
+
+/// The structure `RelationChange` is a relation which differs, from the referenced type
+/// `source` to the holder `target`, with the relationship it had before a change.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct RelationChange {
+    pub source: Vec<String>,
+    pub target: Vec<String>,
+    pub relation: Relation,
+    pub change: Change,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Relation>,
+}
+
+/// The structure `Diff` is the comparison of the model `old` with the model `new`, whose
+/// summary is the changed types and relations.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diff {
+    #[serde(skip)]
+    pub old: Model,
+    #[serde(skip)]
+    pub new: Model,
+    pub types: Vec<TypeChange>,
+    pub relations: Vec<RelationChange>,
+}
+
+/// The function `members` returns the signatures of the members of `node`.
+fn members(node: &Node) -> Vec<String> {
+    node.fields
+        .iter()
+        .enumerate()
+        .map(|(at, field)| {
+            format!(
+                "{}{}: {}",
+                field.vis.keyword(),
+                field.name.clone().unwrap_or_else(|| at.to_string()),
+                field.ty
+            )
+        })
+        .chain(node.variants.iter().map(|variant| match variant.fields.is_empty() {
+            true => variant.name.clone(),
+            false => format!("{}({})", variant.name, variant.fields.join(", ")),
+        }))
+        .chain(node.items.iter().map(Function::declaration))
+        .chain(node.methods.iter().map(Function::declaration))
+        .chain(
+            node.implems
+                .iter()
+                .map(|implem| format!("impl {}", implem.path.join("::"))),
+        )
+        .collect::<Vec<String>>()
+}
+
+/// The function `same` returns the relation of `edges` between the same types as `edge`.
+fn same<'a>(edges: &'a Model, edge: &Edge) -> Option<&'a Edge> {
+    edges
+        .relations()
+        .find(|other| other.source == edge.source && other.target == edge.target)
+}
+
+/// The function `merge` pushes the members of `old` which aren't in `new` after them.
+fn merge<T: Clone + PartialEq>(new: &mut Vec<T>, old: &[T]) {
+    for member in old.iter() {
+        if !new.contains(member) {
+            new.push(member.clone());
+        }
+    }
+}
+
+impl Model {
+    /// The method `diff` returns the comparison of the model with the model `new`, the
+    /// declarations having moved in their files being the same.
+    pub fn diff(&self, new: &Model) -> Diff {
+        let unplaced = |node: &Node| -> Node {
+            Node {
+                span: None,
+                ..node.clone()
+            }
+        };
+        let mut types: Vec<TypeChange> = Vec::new();
+        let mut relations: Vec<RelationChange> = Vec::new();
+
+        for node in new.nodes.iter() {
+            match self.node(&node.path) {
+                None => types.push(TypeChange {
+                    path: node.path.clone(),
+                    kind: node.kind,
+                    change: Change::Added,
+                    added: Vec::new(),
+                    removed: Vec::new(),
+                }),
+                Some(old) if unplaced(old) != unplaced(node) => {
+                    let (before, after): (Vec<String>, Vec<String>) = (members(old), members(node));
+
+                    types.push(TypeChange {
+                        path: node.path.clone(),
+                        kind: node.kind,
+                        change: Change::Changed,
+                        added: after
+                            .iter()
+                            .filter(|member| !before.contains(member))
+                            .cloned()
+                            .collect::<Vec<String>>(),
+                        removed: before
+                            .iter()
+                            .filter(|member| !after.contains(member))
+                            .cloned()
+                            .collect::<Vec<String>>(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for node in self.nodes.iter().filter(|node| new.node(&node.path).is_none()) {
+            types.push(TypeChange {
+                path: node.path.clone(),
+                kind: node.kind,
+                change: Change::Removed,
+                added: Vec::new(),
+                removed: Vec::new(),
+            });
+        }
+        for edge in new.relations() {
+            match same(self, edge) {
+                None => relations.push(RelationChange {
+                    source: edge.source.clone(),
+                    target: edge.target.clone(),
+                    relation: edge.relation,
+                    change: Change::Added,
+                    before: None,
+                }),
+                Some(old) if (old.relation, old.reverse) != (edge.relation, edge.reverse) => {
+                    relations.push(RelationChange {
+                        source: edge.source.clone(),
+                        target: edge.target.clone(),
+                        relation: edge.relation,
+                        change: Change::Changed,
+                        before: Some(old.relation),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for edge in self.relations().filter(|edge| same(new, edge).is_none()) {
+            relations.push(RelationChange {
+                source: edge.source.clone(),
+                target: edge.target.clone(),
+                relation: edge.relation,
+                change: Change::Removed,
+                before: None,
+            });
+        }
+        Diff {
+            old: self.clone(),
+            new: new.clone(),
+            types: types,
+            relations: relations,
+        }
+    }
+}
+
+impl Diff {
+    /// The method `is_empty` returns true when the models have the same types and relations.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty() && self.relations.is_empty()
+    }
+
+    /// The method `change` returns the change of the type `path`, none when it's unchanged.
+    pub fn change(&self, path: &[String]) -> Option<Change> {
+        self.types
+            .iter()
+            .find(|ty| ty.path.as_slice() == path)
+            .map(|ty| ty.change)
+    }
+
+    /// The method `relation_change` returns the change of the relation `edge`, none when
+    /// it's unchanged.
+    pub fn relation_change(&self, edge: &Edge) -> Option<Change> {
+        self.relations
+            .iter()
+            .find(|relation| relation.source == edge.source && relation.target == edge.target)
+            .map(|relation| relation.change)
+    }
+
+    /// The method `nodes` returns the types of both models, a changed type with the members
+    /// it lost after its new ones.
+    pub fn nodes(&self) -> Vec<Node> {
+        self.new
+            .nodes
+            .iter()
+            .map(|node| match self.old.node(&node.path) {
+                Some(old) => {
+                    let mut node: Node = node.clone();
+
+                    merge(&mut node.fields, &old.fields);
+                    merge(&mut node.variants, &old.variants);
+                    merge(&mut node.items, &old.items);
+                    merge(&mut node.methods, &old.methods);
+                    merge(&mut node.implems, &old.implems);
+                    node
+                }
+                None => node.clone(),
+            })
+            .chain(
+                self.old
+                    .nodes
+                    .iter()
+                    .filter(|node| self.new.node(&node.path).is_none())
+                    .cloned(),
+            )
+            .collect::<Vec<Node>>()
+    }
+
+    /// The method `edges` returns the relations of both models, the new one of a changed
+    /// relation.
+    pub fn edges(&self) -> Vec<Edge> {
+        self.new
+            .relations()
+            .chain(self.old.relations().filter(|edge| same(&self.new, edge).is_none()))
+            .cloned()
+            .collect::<Vec<Edge>>()
+    }
+
+    /// The method `to_json` returns the summary of the changes as JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::Model(e.to_string()))
+    }
+}
+
+/// The function `lines` returns the lines of the label of `node`, below its header.
+fn lines(node: &Node, config: &Config) -> HashSet<String> {
+    let (_, compartments) = compartments(node, config);
+
+    compartments
+        .into_iter()
+        .flat_map(|(_, lines)| lines.into_iter())
+        .collect::<HashSet<String>>()
+}
+
+/// The function `label` returns the HTML label of the type `node` of `diff`, its header in
+/// the color of its change and, for a changed type, its new members in green and the lost
+/// ones struck out in red.
+fn label(diff: &Diff, node: &Node, config: &Config) -> String {
+    let (header_bgcolor, compartments) = compartments(node, config);
+    let change: Option<Change> = diff.change(&node.path);
+    let (before, after): (HashSet<String>, HashSet<String>) =
+        match (change, diff.old.node(&node.path), diff.new.node(&node.path)) {
+            (Some(Change::Changed), Some(old), Some(new)) => (lines(old, config), lines(new, config)),
+            _ => (HashSet::new(), HashSet::new()),
+        };
+    let line2html = |line: &String| -> String {
+        match (before.contains(line), after.contains(line)) {
+            (true, false) => format!(
+                "<font color=\"{}\"><s>{}</s></font>",
+                Change::Removed.as_color(),
+                escape_html(line)
+            ),
+            (false, true) => format!(
+                "<font color=\"{}\">{}</font>",
+                Change::Added.as_color(),
+                escape_html(line)
+            ),
+            _ => escape_html(line),
+        }
+    };
+    let mut label: String = format!(
+        "<font face=\"{}\"><table border=\"1\" cellspacing=\"0\" cellpadding=\"10\"><tr><td bgcolor=\"{}\"><b>{}</b></td></tr>",
+        config.font_name,
+        change.map_or(header_bgcolor, |change| change.as_color()),
+        node.name()
+    );
+
+    for (bgcolor, lines) in compartments {
+        label.push_str(&format!(
+            "<tr><td align=\"left\" bgcolor=\"{}\">{}<br align=\"left\"/></td></tr>",
+            bgcolor,
+            lines
+                .iter()
+                .map(|line| line2html(line))
+                .collect::<Vec<String>>()
+                .join("<br align=\"left\"/>\n")
+        ));
+    }
+    label.push_str("</table></font>");
+    label
+}
+
+impl<'a> Labeller<'a, Node, Edge> for WithConfig<'a, Diff> {
+    fn graph_id(&'a self) -> Id<'a> {
+        Id::new("ml").unwrap()
+    }
+
+    fn node_id(&'a self, node: &Node) -> Id<'a> {
+        Id::new(node.id()).unwrap()
+    }
+
+    fn node_shape(&'a self, _node: &Node) -> Option<LabelText<'a>> {
+        Some(LabelText::LabelStr(Cow::from(format!("none"))))
+    }
+
+    fn node_label(&'a self, node: &Node) -> LabelText<'a> {
+        let &WithConfig(diff, config) = self;
+
+        LabelText::HtmlStr(label(diff, node, config).into())
+    }
+
+    fn edge_color(&'a self, edge: &Edge) -> Option<LabelText<'a>> {
+        let &WithConfig(diff, _) = self;
+
+        match diff.relation_change(edge) {
+            Some(change) => Some(LabelText::LabelStr(change.as_color().into())),
+            None => Some(LabelText::LabelStr(format!("{}", "black").into())),
+        }
+    }
+
+    fn edge_end_arrow(&'a self, edge: &Edge) -> Arrow {
+        match (edge.relation, edge.reverse) {
+            (Relation::Association, Relation::Association) => Arrow::none(),
+            (relation, _) => Arrow::from_arrow(relation.as_style()),
+        }
+    }
+
+    fn edge_style(&'a self, edge: &Edge) -> Style {
+        match edge.relation {
+            Relation::Realization | Relation::Dependency => Style::Dashed,
+            _ => Style::None,
+        }
+    }
+}
+
+impl<'a> GraphWalk<'a, Node, Edge> for WithConfig<'a, Diff> {
+    fn nodes(&'a self) -> Nodes<'a, Node> {
+        let &WithConfig(diff, _) = self;
+
+        Cow::Owned(diff.nodes())
+    }
+
+    fn edges(&'a self) -> Edges<'a, Edge> {
+        let &WithConfig(diff, _) = self;
+
+        Cow::Owned(diff.edges())
+    }
+
+    fn source(&self, edge: &Edge) -> Node {
+        let &WithConfig(diff, _) = self;
+
+        diff.new
+            .node(&edge.source)
+            .or_else(|| diff.old.node(&edge.source))
+            .cloned()
+            .unwrap()
+    }
+
+    fn target(&self, edge: &Edge) -> Node {
+        let &WithConfig(diff, _) = self;
+
+        diff.new
+            .node(&edge.target)
+            .or_else(|| diff.old.node(&edge.target))
+            .cloned()
+            .unwrap()
+    }
+}
+
+/// The function `render` writes the graph of both models of `diff` for *Graphviz/Dot* to `w`,
+/// the changed types and relations in the color of their change.
+pub fn render<W: Write>(diff: &Diff, config: &Config, w: &mut W) -> io::Result<()> {
+    dot::render(&WithConfig(diff, config), w)
+}
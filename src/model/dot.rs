@@ -0,0 +1,397 @@
+//! The purpose of this module is to render a model for *Graphiz/Dot*, with the same labels
+//! and arrows as the graphs rendered from the sources.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::core::item::state::DEFAULT_FUNC;
+use crate::core::path2id;
+use crate::dot::{escape_html, Arrow, Edges, GraphWalk, Id, LabelText, Labeller, Nodes, Style};
+use crate::{Config, WithConfig};
+
+use super::{Edge, Kind, Model, Node};
+
+/// The function `url` returns the link of `node` in its source file, from the configured
+/// `src_url_mask`.
+pub(crate) fn url(node: &Node, config: &Config) -> Option<String> {
+    let ty: &str = node.kind.as_str();
+
+    match (config.src_url_mask.is_empty(), &node.span) {
+        (false, &Some(ref span)) => {
+            // scrolltext is for finding eg 'struct MyStruct' in the source file
+            // using the scroll-to-text-fragment feature in chromium browsers.
+            let searchtext = format!("{} {}", ty, node.name());
+            let scrolltext = format!("#:~:text={}", urlencoding::encode(&searchtext));
+            let mut vars = HashMap::new();
+            let file = span.file.to_string_lossy().replace("\\", "/");
+
+            vars.insert("file".to_string(), file.as_str());
+            match strfmt::strfmt(&config.src_url_mask, &vars) {
+                Ok(url) => Some(format!("{}{}", url, scrolltext)),
+                Err(e) => {
+                    eprintln!("invalid src_url_mask. error: {}", e.to_string());
+                    None
+                }
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The function `compartments` returns the header's color and the compartments (color and
+/// lines) of the label of `node`, below its header.
+pub(crate) fn compartments<'a>(node: &Node, config: &'a Config) -> (&'a str, Vec<(&'a str, Vec<String>)>) {
+    let (header_bgcolor, fields_bgcolor, method_bgcolor, implem_bgcolor): (&str, &str, &str, &str) = match node.kind {
+        Kind::Struct => (
+            &config.struct_header_bgcolor,
+            &config.struct_fields_bgcolor,
+            &config.struct_method_bgcolor,
+            &config.struct_implem_bgcolor,
+        ),
+        Kind::Enum => (
+            &config.enum_header_bgcolor,
+            &config.enum_fields_bgcolor,
+            &config.enum_method_bgcolor,
+            &config.enum_implem_bgcolor,
+        ),
+        Kind::Trait => (
+            &config.trait_header_bgcolor,
+            &config.trait_method_bgcolor,
+            &config.trait_method_bgcolor,
+            &config.trait_implem_bgcolor,
+        ),
+    };
+    let mut compartments: Vec<(&str, Vec<String>)> = Vec::new();
+
+    let members: Vec<String> = match node.kind {
+        Kind::Struct if config.include_fields => node
+            .fields
+            .iter()
+            .map(|field| {
+                let name_part = match field.name {
+                    Some(ref n) => format!("{}: ", n),
+                    None => "".to_string(),
+                };
+
+                format!("{} {}{}", field.vis.sign(), name_part, field.ty)
+            })
+            .collect::<Vec<String>>(),
+        Kind::Enum if config.include_fields => node
+            .variants
+            .iter()
+            .map(|variant| {
+                if variant.fields.is_empty() {
+                    variant.name.clone()
+                } else {
+                    format!("{}({})", variant.name, variant.fields.join(", "))
+                }
+            })
+            .collect::<Vec<String>>(),
+        Kind::Trait if config.include_methods => node
+            .items
+            .iter()
+            .map(|item| format!("{} -> {}", item.signature(), item.returns()))
+            .collect::<Vec<String>>(),
+        _ => Vec::new(),
+    };
+
+    if !members.is_empty() || (node.kind == Kind::Trait && config.include_methods) {
+        compartments.push((fields_bgcolor, members));
+    }
+    if !node.methods.is_empty() && config.include_methods {
+        compartments.push((
+            method_bgcolor,
+            node.methods
+                .iter()
+                // like the graphs rendered from the sources, which only tell the public methods.
+                .map(|method| format!("{}{}{}", method.vis.sign(), DEFAULT_FUNC, method.prototype()))
+                .collect::<Vec<String>>(),
+        ));
+    }
+    if !node.implems.is_empty() && config.include_implems {
+        compartments.push((
+            implem_bgcolor,
+            node.implems
+                .iter()
+                .flat_map(|implem| match implem.methods.is_empty() {
+                    // an implementation without method keeps its (empty) line.
+                    true => vec![String::new()],
+                    false => implem
+                        .methods
+                        .iter()
+                        .map(|method| format!("{}{}", DEFAULT_FUNC, method.prototype()))
+                        .collect::<Vec<String>>(),
+                })
+                .collect::<Vec<String>>(),
+        ));
+    }
+    (header_bgcolor, compartments)
+}
+
+impl<'c> fmt::Display for WithConfig<'c, Node> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let &WithConfig(node, config) = self;
+        let (header_bgcolor, compartments) = compartments(node, config);
+        let href = match url(node, config) {
+            Some(url) => format!(" href=\"{}\"", url),
+            None => "".to_string(),
+        };
+
+        // the crate's tag, for the diagrams of a whole workspace.
+        let krate = match node.krate() {
+            "" => "".to_string(),
+            krate => format!(
+                "<tr><td><font point-size=\"9\">{krate}</font></td></tr>",
+                krate = escape_html(krate)
+            ),
+        };
+
+        write!(
+            f,
+            "<font face=\"{font}\"><table border=\"1\" cellspacing=\"0\" cellpadding=\"10\"{href}>{krate}<tr><td bgcolor=\"{bgcolor}\"><b>{name}</b></td></tr>",
+            font = config.font_name,
+            href = href,
+            krate = krate,
+            bgcolor = header_bgcolor,
+            name = node.name(),
+        )?;
+
+        for (bgcolor, lines) in compartments {
+            write!(
+                f,
+                "<tr><td align=\"left\" bgcolor=\"{bgcolor}\">{lines}<br align=\"left\"/></td></tr>",
+                bgcolor = bgcolor,
+                lines = lines
+                    .iter()
+                    .map(|line| escape_html(line))
+                    .collect::<Vec<String>>()
+                    .join("<br align=\"left\"/>\n")
+            )?;
+        }
+
+        write!(f, "</table></font>")
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", WithConfig(self, &Config::global()))
+    }
+}
+
+impl<'a> Labeller<'a, Node, Edge> for WithConfig<'a, Model> {
+    fn graph_id(&'a self) -> Id<'a> {
+        Id::new("ml").unwrap()
+    }
+
+    fn node_id(&'a self, node: &Node) -> Id<'a> {
+        Id::new(node.id()).unwrap()
+    }
+
+    fn node_shape(&'a self, node: &Node) -> Option<LabelText<'a>> {
+        let &WithConfig(model, _) = self;
+
+        match model.stubs.contains(&node.path) {
+            true => Some(LabelText::LabelStr(Cow::from(format!("box")))),
+            false => Some(LabelText::LabelStr(Cow::from(format!("none")))),
+        }
+    }
+
+    fn node_label(&'a self, node: &Node) -> LabelText<'a> {
+        let &WithConfig(model, config) = self;
+
+        match model.stubs.contains(&node.path) {
+            // a type cut off by the focus shows only its name.
+            true => LabelText::LabelStr(Cow::from(node.name().to_string())),
+            false => LabelText::HtmlStr(format!("{}", WithConfig(node, config)).into()),
+        }
+    }
+
+    fn node_style(&'a self, node: &Node) -> Style {
+        let &WithConfig(model, _) = self;
+
+        match model.stubs.contains(&node.path) {
+            true => Style::Dashed,
+            false => Style::None,
+        }
+    }
+
+    fn node_color(&'a self, node: &Node) -> Option<LabelText<'a>> {
+        let &WithConfig(model, _) = self;
+
+        match model.stubs.contains(&node.path) {
+            true => Some(LabelText::LabelStr(Cow::from(format!("grey")))),
+            false => None,
+        }
+    }
+
+    fn edge_color(&'a self, _edge: &Edge) -> Option<LabelText<'a>> {
+        Some(LabelText::LabelStr(format!("{}", "black").into()))
+    }
+
+    fn edge_end_arrow(&'a self, edge: &Edge) -> Arrow {
+        match (edge.relation, edge.reverse) {
+            (Relation::Association, Relation::Association) => Arrow::none(),
+            (relation, _) => Arrow::from_arrow(relation.as_style()),
+        }
+    }
+
+    fn edge_style(&'a self, edge: &Edge) -> Style {
+        match edge.relation {
+            Relation::Realization | Relation::Dependency => Style::Dashed,
+            _ => Style::None,
+        }
+    }
+}
+
+impl<'a> GraphWalk<'a, Node, Edge> for WithConfig<'a, Model> {
+    fn nodes(&'a self) -> Nodes<'a, Node> {
+        let &WithConfig(model, _) = self;
+
+        Cow::Borrowed(model.nodes.as_slice())
+    }
+
+    fn edges(&'a self) -> Edges<'a, Edge> {
+        let &WithConfig(model, _) = self;
+
+        Cow::Owned(model.relations().cloned().collect::<Vec<Edge>>())
+    }
+
+    fn source(&self, edge: &Edge) -> Node {
+        let &WithConfig(model, _) = self;
+
+        model.node(&edge.source).cloned().unwrap()
+    }
+
+    fn target(&self, edge: &Edge) -> Node {
+        let &WithConfig(model, _) = self;
+
+        model.node(&edge.target).cloned().unwrap()
+    }
+}
+
+/// The function `statement` returns the statement of the node `node`, like `dot::render`.
+fn statement(graph: &WithConfig<Model>, node: &Node) -> String {
+    let mut text: String = format!(
+        "{}[label={}]",
+        graph.node_id(node).as_slice(),
+        graph.node_label(node).to_dot_string()
+    );
+
+    match graph.node_style(node) {
+        Style::None => {}
+        style => text.push_str(&format!("[style=\"{}\"]", style.as_slice())),
+    }
+    if let Some(color) = graph.node_color(node) {
+        text.push_str(&format!("[color={}]", color.to_dot_string()));
+    }
+    if let Some(shape) = graph.node_shape(node) {
+        text.push_str(&format!("[shape={}]", shape.to_dot_string()));
+    }
+    text.push(';');
+    text
+}
+
+/// The function `link` returns the statement of the edge `edge`, like `dot::render`.
+fn link(graph: &WithConfig<Model>, edge: &Edge) -> String {
+    let mut text: String = format!(
+        "{} -> {}[label={}]",
+        graph.node_id(&graph.source(edge)).as_slice(),
+        graph.node_id(&graph.target(edge)).as_slice(),
+        graph.edge_label(edge).to_dot_string()
+    );
+    let end: String = graph.edge_end_arrow(edge).to_dot_string();
+    let start: String = graph.edge_start_arrow(edge).to_dot_string();
+
+    match graph.edge_style(edge) {
+        Style::None => {}
+        style => text.push_str(&format!("[style=\"{}\"]", style.as_slice())),
+    }
+    if let Some(color) = graph.edge_color(edge) {
+        text.push_str(&format!("[color={}]", color.to_dot_string()));
+    }
+    // the default arrows are left out.
+    match (end.is_empty(), start.is_empty()) {
+        (true, true) => {}
+        (false, true) => text.push_str(&format!("[arrowhead=\"{}\"]", end)),
+        (true, false) => text.push_str(&format!("[ dir=\"both\" arrowtail=\"{}\"]", start)),
+        (false, false) => text.push_str(&format!(
+            "[arrowhead=\"{}\" dir=\"both\" arrowtail=\"{}\"]",
+            end, start
+        )),
+    }
+    text.push(';');
+    text
+}
+
+/// The function `cluster` writes the types of the module `module` and the clusters of its
+/// submodules, the types below `depth` being merged into the module at `depth`.
+fn cluster<W: Write>(
+    w: &mut W,
+    graph: &WithConfig<Model>,
+    module: &[String],
+    depth: usize,
+    indent: usize,
+) -> io::Result<()> {
+    let &WithConfig(model, _) = graph;
+    let tab: String = "    ".repeat(indent);
+    // the module of a type, cut at `depth` below the crate's tag.
+    let owner = |node: &Node| -> Vec<String> {
+        let path: &[String] = &node.path[..node.path.len() - 1];
+
+        path[..path.len().min(depth + 1)].to_vec()
+    };
+    let mut children: Vec<Vec<String>> = model
+        .nodes
+        .iter()
+        .map(|node| owner(node))
+        .filter(|path| path.len() > module.len() && path.starts_with(module))
+        .map(|path| path[..module.len() + 1].to_vec())
+        .collect::<Vec<Vec<String>>>();
+
+    children.sort();
+    children.dedup();
+    for node in model.nodes.iter().filter(|node| owner(node).as_slice() == module) {
+        writeln!(w, "{}{}", tab, statement(graph, node))?;
+    }
+    for child in children {
+        match child.as_slice() {
+            // the crate root of a single crate isn't a cluster.
+            [krate] if krate.is_empty() => cluster(w, graph, &child, depth, indent)?,
+            _ => {
+                writeln!(w, "{}subgraph cluster_{} {{", tab, &path2id(&child)[2..])?;
+                writeln!(
+                    w,
+                    "{}    label=\"{}\";",
+                    tab,
+                    child
+                        .iter()
+                        .filter(|segment| !segment.is_empty())
+                        .map(String::as_str)
+                        .collect::<Vec<&str>>()
+                        .join("::")
+                )?;
+                cluster(w, graph, &child, depth, indent + 1)?;
+                writeln!(w, "{}}}", tab)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The function `render_clusters` writes the graph of `model` for *Graphviz/Dot* to `w` like
+/// `dot::render`, with the types in nested clusters of their modules: a crate, then its
+/// modules down to `depth` segments, whose submodules are merged into them.
+pub fn render_clusters<W: Write>(model: &Model, config: &Config, depth: usize, w: &mut W) -> io::Result<()> {
+    let graph: WithConfig<Model> = WithConfig(model, config);
+
+    writeln!(w, "digraph {} {{", graph.graph_id().as_slice())?;
+    cluster(w, &graph, &[], depth, 1)?;
+    for edge in graph.edges().iter() {
+        writeln!(w, "    {}", link(&graph, edge))?;
+    }
+    writeln!(w, "}}")
+}
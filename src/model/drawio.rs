@@ -0,0 +1,153 @@
+//! The purpose of this module is to write a model as a *draw.io* (diagrams.net) mxGraph file,
+//! whose class shapes are placed by the built-in layered layout.
+
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::core::path2id;
+use crate::dot::escape_html;
+use crate::Config;
+
+use super::dot::compartments;
+use super::layout::{self, Rect};
+use super::{Edge, Kind, Model, Node};
+
+/// The height of the header of a class shape.
+const HEADER: f64 = 26.0;
+/// The height of a line of a compartment.
+const LINE: f64 = 18.0;
+/// The average width of a character of the 12px font.
+const CHAR: f64 = 7.0;
+
+/// The function `title` returns the header of the class shape of `node`, with the
+/// stereotype of the enumerations and traits.
+fn title(node: &Node) -> String {
+    let name: String = match node.params.is_empty() {
+        true => node.name().to_string(),
+        false => format!("{}<{}>", node.name(), node.params.join(", ")),
+    };
+
+    match node.kind {
+        Kind::Struct => name,
+        Kind::Enum => format!("<<enumeration>> {}", name),
+        Kind::Trait => format!("<<interface>> {}", name),
+    }
+}
+
+/// The function `size` returns the width and height of the class shape of `node`.
+fn size(node: &Node, config: &Config) -> (f64, f64) {
+    let (_, compartments) = compartments(node, config);
+    let longest: usize = compartments
+        .iter()
+        .flat_map(|&(_, ref lines)| lines.iter().map(|line| line.chars().count()))
+        .chain(Some(title(node).chars().count()))
+        .max()
+        .unwrap_or_default();
+    let height: f64 = compartments
+        .iter()
+        .map(|&(_, ref lines)| lines.len() as f64 * LINE + 8.0)
+        .sum::<f64>();
+
+    ((longest as f64 * CHAR + 16.0).max(120.0), HEADER + height)
+}
+
+/// The function `shape` writes the class shape of `node` at `rect`.
+fn shape<W: Write>(w: &mut W, node: &Node, config: &Config, rect: &Rect) -> io::Result<()> {
+    let id: String = node.id();
+    let (header, compartments) = compartments(node, config);
+
+    writeln!(
+        w,
+        "        <mxCell id=\"{id}\" value=\"{value}\" style=\"swimlane;fontStyle=1;align=center;verticalAlign=top;childLayout=stackLayout;horizontal=1;startSize={header};horizontalStack=0;resizeParent=1;resizeParentMax=0;resizeLast=0;collapsible=1;marginBottom=0;fillColor={bgcolor};fontFamily={font};html=1;\" vertex=\"1\" parent=\"1\">",
+        id = id,
+        value = escape_html(&escape_html(&title(node))),
+        header = HEADER,
+        bgcolor = header,
+        font = config.font_name
+    )?;
+    writeln!(
+        w,
+        "          <mxGeometry x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" as=\"geometry\"/>",
+        rect.x, rect.y, rect.width, rect.height
+    )?;
+    writeln!(w, "        </mxCell>")?;
+
+    let mut y: f64 = HEADER;
+
+    for (at, &(bgcolor, ref lines)) in compartments.iter().enumerate() {
+        let height: f64 = lines.len() as f64 * LINE + 8.0;
+        let value: String = lines
+            .iter()
+            .map(|line| escape_html(line))
+            .collect::<Vec<String>>()
+            .join("<br>");
+
+        writeln!(
+            w,
+            "        <mxCell id=\"{id}.{at}\" value=\"{value}\" style=\"text;strokeColor=default;fillColor={bgcolor};align=left;verticalAlign=top;spacingLeft=4;spacingRight=4;overflow=hidden;rotatable=0;points=[[0,0.5],[1,0.5]];portConstraint=eastwest;fontFamily={font};html=1;\" vertex=\"1\" parent=\"{id}\">",
+            id = id,
+            at = at,
+            value = escape_html(&value),
+            bgcolor = bgcolor,
+            font = config.font_name
+        )?;
+        writeln!(
+            w,
+            "          <mxGeometry y=\"{}\" width=\"{}\" height=\"{}\" as=\"geometry\"/>",
+            y, rect.width, height
+        )?;
+        writeln!(w, "        </mxCell>")?;
+        y += height;
+    }
+    Ok(())
+}
+
+/// The function `style` returns the style of the edge of `edge`, whose arrow is drawn
+/// at the holder like `Relation::as_style`.
+fn style(edge: &Edge) -> &'static str {
+    match (edge.relation, edge.reverse) {
+        (Relation::Association, Relation::Association) => "endArrow=none;html=1;",
+        (Relation::Composition, _) => "endArrow=diamondThin;endFill=1;endSize=14;html=1;",
+        (Relation::Aggregation, _) => "endArrow=diamondThin;endFill=0;endSize=14;html=1;",
+        (Relation::Realization, _) => "endArrow=block;endFill=0;dashed=1;html=1;",
+        (Relation::Dependency, _) => "endArrow=open;endFill=0;dashed=1;html=1;",
+        (Relation::Association, _) => "endArrow=open;endFill=0;html=1;",
+        (Relation::None, _) => "endArrow=none;html=1;",
+    }
+}
+
+/// The function `render` writes the *draw.io* file of `model` to `w`.
+pub fn render<W: Write>(model: &Model, config: &Config, w: &mut W) -> io::Result<()> {
+    let sizes: Vec<(f64, f64)> = model
+        .nodes
+        .iter()
+        .map(|node| size(node, config))
+        .collect::<Vec<(f64, f64)>>();
+    let rects: Vec<Rect> = layout::layered(model, &sizes);
+
+    writeln!(w, "<mxfile host=\"rust2uml\">")?;
+    writeln!(w, "  <diagram id=\"ml\" name=\"ml\">")?;
+    writeln!(w, "    <mxGraphModel grid=\"1\" gridSize=\"10\" guides=\"1\" arrows=\"1\" connect=\"1\" page=\"0\">")?;
+    writeln!(w, "      <root>")?;
+    writeln!(w, "        <mxCell id=\"0\"/>")?;
+    writeln!(w, "        <mxCell id=\"1\" parent=\"0\"/>")?;
+    for (node, rect) in model.nodes.iter().zip(rects.iter()) {
+        shape(w, node, config, rect)?;
+    }
+    for (at, edge) in model.relations().enumerate() {
+        writeln!(
+            w,
+            "        <mxCell id=\"ed{}\" style=\"{}\" edge=\"1\" parent=\"1\" source=\"{}\" target=\"{}\">",
+            at,
+            style(edge),
+            path2id(&edge.source),
+            path2id(&edge.target)
+        )?;
+        writeln!(w, "          <mxGeometry relative=\"1\" as=\"geometry\"/>")?;
+        writeln!(w, "        </mxCell>")?;
+    }
+    writeln!(w, "      </root>")?;
+    writeln!(w, "    </mxGraphModel>")?;
+    writeln!(w, "  </diagram>")?;
+    writeln!(w, "</mxfile>")
+}
@@ -0,0 +1,221 @@
+//! The purpose of this module is to leave types out of the diagrams (generated modules, test
+//! helpers...) by rules on their module, name and kind, before their relations are computed.
+
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::{Error, Result};
+
+use super::{Edge, Model, Node, Package};
+
+/// The enumeration `Target` is the part of a type that a rule matches.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Target {
+    /// The path of its module from the crate's root, like `proto::gen` (empty for the root),
+    /// or in a workspace prefixed by the crate's tag, like `bin:ml::proto::gen`.
+    Module,
+    /// Its name, like `Circle`.
+    Name,
+    /// Its kind: `struct`, `enum` or `trait`.
+    Kind,
+}
+
+/// The structure `Rule` is a pattern on a part of the types, written `target:pattern` like
+/// `module:proto::*`, `kind:enum` or `name:/Error$/`.
+///
+/// A pattern between slashes is a regular expression, searched in the text; another one is a
+/// glob on the whole text, where `*` is any sequence and `?` any character, and whose final
+/// `::*` also matches the module itself (`proto::*` matches `proto` and `proto::gen`).
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub target: Target,
+    pub pattern: Regex,
+}
+
+impl Rule {
+    /// The method `is_match` returns true when the pattern matches `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.pattern.is_match(text)
+    }
+}
+
+/// The function `glob2regex` returns the regular expression of the whole text matched by
+/// the glob `glob`.
+fn glob2regex(glob: &str) -> String {
+    let (glob, descendants): (&str, bool) = match glob.strip_suffix("::*") {
+        Some(module) => (module, true),
+        None => (glob, false),
+    };
+    let mut regex: String = "^".to_string();
+    let mut literal: String = String::new();
+
+    for c in glob.chars() {
+        match c {
+            '*' | '?' => {
+                regex.push_str(&regex::escape(&literal));
+                regex.push_str(if c == '*' { ".*" } else { "." });
+                literal.clear();
+            }
+            c => literal.push(c),
+        }
+    }
+    regex.push_str(&regex::escape(&literal));
+    if descendants {
+        regex.push_str("(::.*)?");
+    }
+    regex.push('$');
+    regex
+}
+
+/// The function `unclosed` returns true when the rule `rule` is a regular expression whose
+/// closing slash is still to come.
+fn unclosed(rule: &str) -> bool {
+    match rule.trim().split_once(':') {
+        Some((_, pattern)) => pattern.starts_with('/') && (pattern.len() == 1 || !pattern.ends_with('/')),
+        None => false,
+    }
+}
+
+impl Rule {
+    /// The method `list` returns the comma-separated rules `rules`, whose regular expressions
+    /// keep their commas, like `name:/^E{1,2}rror$/,kind:enum`.
+    pub fn list(rules: &str) -> Result<Vec<Rule>> {
+        let mut list: Vec<String> = Vec::new();
+
+        for part in rules.split(',') {
+            match list.last_mut() {
+                Some(rule) if unclosed(rule) => {
+                    rule.push(',');
+                    rule.push_str(part);
+                }
+                _ => list.push(part.to_string()),
+            }
+        }
+        list.iter()
+            .map(|rule| rule.trim().parse::<Rule>())
+            .collect::<Result<Vec<Rule>>>()
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(rule: &str) -> Result<Rule> {
+        let (target, pattern): (&str, &str) = match rule.split_once(':') {
+            Some(parts) => parts,
+            None => return Err(Error::Config(format!("rule {} isn't target:pattern", rule))),
+        };
+        let target: Target = match target {
+            "module" | "mod" => Target::Module,
+            "name" => Target::Name,
+            "kind" => Target::Kind,
+            target => return Err(Error::Config(format!("unknown rule target {}", target))),
+        };
+        let regex: String = match pattern.len() > 1 && pattern.starts_with('/') && pattern.ends_with('/') {
+            true => pattern[1..pattern.len() - 1].to_string(),
+            false => glob2regex(pattern),
+        };
+
+        Regex::new(&regex)
+            .map(|pattern: Regex| Rule {
+                target: target,
+                pattern: pattern,
+            })
+            .map_err(|e| Error::Config(format!("rule {}: {}", rule, e)))
+    }
+}
+
+/// The structure `Filter` is the types to draw: a type is left out when an `exclude` rule
+/// matches it, or when it matches none of the `include` rules of a target (the rules of a
+/// target are alternatives, each target with rules being required).
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub include: Vec<Rule>,
+    pub exclude: Vec<Rule>,
+}
+
+impl Filter {
+    /// The method `is_empty` returns true when the filter keeps every type.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// The method `allows` returns true when the rules of `target` keep `texts`, the ways to
+    /// write a part, a rule matching when it matches one of them.
+    fn allows(&self, target: Target, texts: &[&str]) -> bool {
+        let mut include = self.include.iter().filter(|rule| rule.target == target).peekable();
+
+        (include.peek().is_none() || include.any(|rule| texts.iter().any(|text| rule.is_match(text))))
+            && !self
+                .exclude
+                .iter()
+                .any(|rule| rule.target == target && texts.iter().any(|text| rule.is_match(text)))
+    }
+
+    /// The method `accepts_module` returns true when the module rules keep the module `path`
+    /// (crate's tag and segments), written from the crate's root or, when the crate is tagged,
+    /// prefixed by its tag so a rule can tell the crates of a workspace apart.
+    pub fn accepts_module(&self, path: &[String]) -> bool {
+        let module: String = path
+            .iter()
+            .skip(1)
+            .map(String::as_str)
+            .collect::<Vec<&str>>()
+            .join("::");
+
+        match path.first() {
+            Some(krate) if !krate.is_empty() => self.allows(Target::Module, &[&module, &path.join("::")]),
+            _ => self.allows(Target::Module, &[&module]),
+        }
+    }
+
+    /// The method `accepts` returns true when the filter keeps the type `path` (crate's tag,
+    /// module's segments and name) of kind `kind`.
+    pub fn accepts(&self, path: &[String], kind: &str) -> bool {
+        match path.split_last() {
+            Some((name, module)) => {
+                self.accepts_module(module)
+                    && self.allows(Target::Name, &[name.as_str()])
+                    && self.allows(Target::Kind, &[kind])
+            }
+            None => false,
+        }
+    }
+}
+
+impl Model {
+    /// The method `filter` returns the model without the types left out by `filter`, nor
+    /// their relations, nor the modules left out by its module rules.
+    pub fn filter(&self, filter: &Filter) -> Model {
+        let nodes: Vec<Node> = self
+            .nodes
+            .iter()
+            .filter(|node| filter.accepts(&node.path, node.kind.as_str()))
+            .cloned()
+            .collect::<Vec<Node>>();
+        let kept = |path: &[String]| nodes.iter().any(|node| node.path.as_slice() == path);
+
+        Model {
+            packages: self
+                .packages
+                .iter()
+                .filter(|package| filter.accepts_module(&package.path))
+                .cloned()
+                .collect::<Vec<Package>>(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|edge| kept(&edge.source) && kept(&edge.target))
+                .cloned()
+                .collect::<Vec<Edge>>(),
+            stubs: self
+                .stubs
+                .iter()
+                .filter(|stub| kept(stub))
+                .cloned()
+                .collect::<Vec<Vec<String>>>(),
+            nodes: nodes,
+        }
+    }
+}
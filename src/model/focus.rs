@@ -0,0 +1,163 @@
+//! The purpose of this module is to cut a model down to the types within some relations of
+//! root types, the neighbours beyond being kept as stubs.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::{Error, Result};
+
+use super::{Edge, Model, Node};
+
+/// The enumeration `Direction` is the way the relations are followed from the roots.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    /// Towards the types which refer to a type (its holders, implementors...).
+    Incoming,
+    /// Towards the types referred to by a type (its fields, traits...).
+    Outgoing,
+    Both,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::Both
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(direction: &str) -> Result<Direction> {
+        match direction {
+            "incoming" | "in" => Ok(Direction::Incoming),
+            "outgoing" | "out" => Ok(Direction::Outgoing),
+            "both" => Ok(Direction::Both),
+            direction => Err(Error::Config(format!("unknown direction {}", direction))),
+        }
+    }
+}
+
+/// The structure `Focus` is the part of a model to render: the types within `hops`
+/// relations of the types `roots` (paths like `shape::Circle`, or names), in `direction`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Focus {
+    pub roots: Vec<String>,
+    pub hops: usize,
+    pub direction: Direction,
+}
+
+impl<'a> From<(&'a [&'a str], usize, Direction)> for Focus {
+    /// The constructor method `from` returns the focus on `roots`.
+    fn from((roots, hops, direction): (&'a [&'a str], usize, Direction)) -> Focus {
+        Focus {
+            roots: roots.iter().map(|root| root.to_string()).collect::<Vec<String>>(),
+            hops: hops,
+            direction: direction,
+        }
+    }
+}
+
+impl Model {
+    /// The method `lookup` returns the type of path `name` (`shape::Circle`, the crate's
+    /// tag first in a workspace), or else the only type named `name`, an error listing the
+    /// types of this name when there are several.
+    pub fn lookup(&self, name: &str) -> Result<&Node> {
+        let path = |node: &Node| -> String {
+            node.path
+                .iter()
+                .filter(|segment| !segment.is_empty())
+                .map(String::as_str)
+                .collect::<Vec<&str>>()
+                .join("::")
+        };
+
+        if let Some(node) = self.nodes.iter().find(|node| path(node) == name) {
+            return Ok(node);
+        }
+        let named: Vec<&Node> = self
+            .nodes
+            .iter()
+            .filter(|node| node.name() == name)
+            .collect::<Vec<&Node>>();
+
+        match named.as_slice() {
+            &[] => Err(Error::Model(format!("unknown type {}", name))),
+            &[node] => Ok(node),
+            nodes => Err(Error::Model(format!(
+                "ambiguous type {}, one of {}",
+                name,
+                nodes.iter().map(|node| path(node)).collect::<Vec<String>>().join(", ")
+            ))),
+        }
+    }
+
+    /// The method `focus` returns the types within the hops of `focus` from its roots with
+    /// their relations, and the neighbours one hop further as stubs (see `Model::stubs`).
+    pub fn focus(&self, focus: &Focus) -> Result<Model> {
+        let mut distances: HashMap<&[String], usize> = HashMap::new();
+        let mut frontier: Vec<&[String]> = Vec::new();
+
+        for root in focus.roots.iter() {
+            let node: &Node = self.lookup(root)?;
+
+            distances.insert(&node.path, 0);
+            frontier.push(&node.path);
+        }
+        // The breadth-first walk goes one hop beyond `hops`, to the stubs.
+        for hop in 1..=focus.hops + 1 {
+            let mut next: Vec<&[String]> = Vec::new();
+
+            for path in frontier {
+                for edge in self.relations() {
+                    // a holder (target) refers to the referenced type (source).
+                    let neighbour: Option<&[String]> = match focus.direction {
+                        Direction::Outgoing if edge.target.as_slice() == path => Some(edge.source.as_slice()),
+                        Direction::Incoming if edge.source.as_slice() == path => Some(edge.target.as_slice()),
+                        Direction::Both if edge.target.as_slice() == path => Some(edge.source.as_slice()),
+                        Direction::Both if edge.source.as_slice() == path => Some(edge.target.as_slice()),
+                        _ => None,
+                    };
+
+                    if let Some(neighbour) = neighbour {
+                        if !distances.contains_key(neighbour) {
+                            distances.insert(neighbour, hop);
+                            next.push(neighbour);
+                        }
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        let inside = |path: &[String]| distances.get(path).map(|&distance| distance <= focus.hops) == Some(true);
+        let stub = |path: &[String]| distances.get(path) == Some(&(focus.hops + 1));
+        let edges: Vec<Edge> = self
+            .relations()
+            .filter(|edge| match (inside(&edge.source), inside(&edge.target), focus.direction) {
+                (true, true, _) => true,
+                // the relation of a stub with the type it was reached from.
+                (false, true, Direction::Outgoing) | (false, true, Direction::Both) => stub(&edge.source),
+                (true, false, Direction::Incoming) | (true, false, Direction::Both) => stub(&edge.target),
+                _ => false,
+            })
+            .cloned()
+            .collect::<Vec<Edge>>();
+
+        Ok(Model {
+            packages: self.packages.clone(),
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|node| distances.contains_key(node.path.as_slice()))
+                .cloned()
+                .collect::<Vec<Node>>(),
+            edges: edges,
+            stubs: self
+                .nodes
+                .iter()
+                .filter(|node| stub(&node.path))
+                .map(|node| node.path.clone())
+                .collect::<Vec<Vec<String>>>(),
+        })
+    }
+}
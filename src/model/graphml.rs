@@ -0,0 +1,94 @@
+//! The purpose of this module is to write the type graph of a model as *GraphML*, for yEd
+//! and the graph-analysis tools (Gephi, networkx...).
+
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::core::path2id;
+use crate::dot::escape_html;
+
+use super::{Model, Node, Visibility};
+
+/// The data keys of the nodes and of the edges: identifier, domain, name and type.
+const KEYS: [(&'static str, &'static str, &'static str); 12] = [
+    ("name", "node", "string"),
+    ("kind", "node", "string"),
+    ("krate", "node", "string"),
+    ("module", "node", "string"),
+    ("visibility", "node", "string"),
+    ("params", "node", "int"),
+    ("fields", "node", "int"),
+    ("variants", "node", "int"),
+    ("methods", "node", "int"),
+    ("implems", "node", "int"),
+    ("relation", "edge", "string"),
+    ("bidirectional", "edge", "boolean"),
+];
+
+/// The function `data` returns the data of `node` by key.
+fn data(node: &Node) -> Vec<(&'static str, String)> {
+    vec![
+        ("name", node.name().to_string()),
+        ("kind", node.kind.as_str().to_string()),
+        ("krate", node.krate().to_string()),
+        ("module", node.module().join("::")),
+        (
+            "visibility",
+            match node.vis {
+                Visibility::Public => "pub".to_string(),
+                Visibility::Crate => "pub(crate)".to_string(),
+                Visibility::Restricted(ref path) => format!("pub(in {})", path),
+                Visibility::Inherited => "private".to_string(),
+            },
+        ),
+        ("params", node.params.len().to_string()),
+        ("fields", node.fields.len().to_string()),
+        ("variants", node.variants.len().to_string()),
+        ("methods", (node.items.len() + node.methods.len()).to_string()),
+        ("implems", node.implems.len().to_string()),
+    ]
+}
+
+/// The function `render` writes the *GraphML* document of `model` to `w`.
+pub fn render<W: Write>(model: &Model, w: &mut W) -> io::Result<()> {
+    writeln!(w, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(
+        w,
+        "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd\">"
+    )?;
+    for &(id, domain, ty) in KEYS.iter() {
+        writeln!(
+            w,
+            "  <key id=\"{id}\" for=\"{domain}\" attr.name=\"{id}\" attr.type=\"{ty}\"/>",
+            id = id,
+            domain = domain,
+            ty = ty
+        )?;
+    }
+    writeln!(w, "  <graph id=\"ml\" edgedefault=\"directed\">")?;
+    for node in model.nodes.iter() {
+        writeln!(w, "    <node id=\"{}\">", node.id())?;
+        for (key, value) in data(node) {
+            writeln!(w, "      <data key=\"{}\">{}</data>", key, escape_html(&value))?;
+        }
+        writeln!(w, "    </node>")?;
+    }
+    for (at, edge) in model.relations().enumerate() {
+        writeln!(
+            w,
+            "    <edge id=\"ed{}\" source=\"{}\" target=\"{}\">",
+            at,
+            path2id(&edge.source),
+            path2id(&edge.target)
+        )?;
+        writeln!(w, "      <data key=\"relation\">{}</data>", edge.relation.as_str())?;
+        writeln!(
+            w,
+            "      <data key=\"bidirectional\">{}</data>",
+            edge.relation == Relation::Association && edge.reverse == Relation::Association
+        )?;
+        writeln!(w, "    </edge>")?;
+    }
+    writeln!(w, "  </graph>")?;
+    writeln!(w, "</graphml>")
+}
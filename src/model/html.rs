@@ -0,0 +1,299 @@
+//! The purpose of this module is to write a model as one self-contained HTML page: the
+//! structured vector graphics with a script to pan, zoom, search and inspect the types.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::Config;
+
+use super::dot::url;
+use super::{Function, Kind, Model, Node};
+
+/// The style of the page.
+const STYLE: &'static str = r#"html, body { margin: 0; height: 100%; font-family: sans-serif; font-size: 14px; }
+body { display: flex; }
+#ml-main { flex: 1; display: flex; flex-direction: column; min-width: 0; }
+#ml-bar { padding: 6px 10px; border-bottom: 1px solid #ccc; background: #f7f7f7; }
+#ml-search { width: 240px; padding: 4px; }
+#ml-canvas { flex: 1; overflow: hidden; cursor: grab; }
+#ml-canvas.ml-dragging { cursor: grabbing; }
+#ml-canvas svg { width: 100%; height: 100%; }
+#ml-panel { width: 320px; overflow: auto; padding: 0 12px; border-left: 1px solid #ccc; background: #fafafa; }
+#ml-panel h2 { font-size: 16px; }
+#ml-panel h3 { font-size: 14px; margin-bottom: 4px; }
+#ml-panel ul { margin: 0; padding-left: 18px; font-family: monospace; }
+g.node { cursor: pointer; }
+.ml-match rect, .ml-match polygon { stroke: #d62728; stroke-width: 3px; }
+.ml-selected rect, .ml-selected polygon { stroke: #1f77b4; stroke-width: 3px; }
+.ml-outgoing path, .ml-outgoing polygon, .ml-outgoing polyline { stroke: #2ca02c; stroke-width: 2.5px; }
+.ml-incoming path, .ml-incoming polygon, .ml-incoming polyline { stroke: #ff7f0e; stroke-width: 2.5px; }
+"#;
+
+/// The script of the page, which reads the types from `ml-data`. The nodes are found by
+/// their identifier (built-in backend) or their title (*Graphviz*), the edges by their
+/// `data-source`/`data-target` or their title `source->target`.
+const SCRIPT: &'static str = r#"(function () {
+  var data = JSON.parse(document.getElementById('ml-data').textContent);
+  var canvas = document.getElementById('ml-canvas');
+  var svg = canvas.querySelector('svg');
+  var panel = document.getElementById('ml-panel');
+  var nodes = {};
+  var edges = [];
+
+  Array.prototype.forEach.call(svg.querySelectorAll('g.node'), function (g) {
+    var title = g.querySelector('title');
+    var id = data[g.id] ? g.id : (title ? title.textContent.trim() : '');
+
+    if (data[id]) {
+      nodes[id] = g;
+      g.setAttribute('data-id', id);
+    }
+  });
+  Array.prototype.forEach.call(svg.querySelectorAll('g.edge'), function (g) {
+    var source = g.getAttribute('data-source');
+    var target = g.getAttribute('data-target');
+
+    if (!source) {
+      var title = g.querySelector('title');
+      var ends = (title ? title.textContent : '').split('->');
+
+      source = ends[0].trim();
+      target = (ends[1] || '').trim();
+    }
+    edges.push({ g: g, source: source, target: target });
+  });
+
+  // Pan and zoom move the view box, which keeps the drawing sharp.
+  var box = svg.viewBox.baseVal;
+  var view = box && box.width
+    ? { x: box.x, y: box.y, w: box.width, h: box.height }
+    : { x: 0, y: 0, w: svg.width.baseVal.value, h: svg.height.baseVal.value };
+  var start = null;
+  var down = null;
+  var moved = false;
+
+  svg.removeAttribute('width');
+  svg.removeAttribute('height');
+  function apply() {
+    svg.setAttribute('viewBox', [view.x, view.y, view.w, view.h].join(' '));
+  }
+  function point(e) {
+    var p = svg.createSVGPoint();
+
+    p.x = e.clientX;
+    p.y = e.clientY;
+    return p.matrixTransform(svg.getScreenCTM().inverse());
+  }
+  apply();
+  svg.addEventListener('wheel', function (e) {
+    var p = point(e);
+    var k = e.deltaY < 0 ? 0.8 : 1.25;
+
+    e.preventDefault();
+    view.x = p.x - (p.x - view.x) * k;
+    view.y = p.y - (p.y - view.y) * k;
+    view.w *= k;
+    view.h *= k;
+    apply();
+  }, { passive: false });
+  svg.addEventListener('mousedown', function (e) {
+    start = point(e);
+    down = { x: e.clientX, y: e.clientY };
+    moved = false;
+    canvas.classList.add('ml-dragging');
+  });
+  window.addEventListener('mousemove', function (e) {
+    if (start) {
+      var p = point(e);
+
+      // A click may move the pointer by a few pixels.
+      if (Math.abs(e.clientX - down.x) + Math.abs(e.clientY - down.y) > 3) {
+        moved = true;
+      }
+      view.x -= p.x - start.x;
+      view.y -= p.y - start.y;
+      apply();
+    }
+  });
+  window.addEventListener('mouseup', function () {
+    start = null;
+    canvas.classList.remove('ml-dragging');
+  });
+
+  // The search highlights the types whose name or path contains the text.
+  document.getElementById('ml-search').addEventListener('input', function () {
+    var text = this.value.trim().toLowerCase();
+
+    Object.keys(nodes).forEach(function (id) {
+      var hit = text !== '' && (data[id].name.toLowerCase().indexOf(text) >= 0 ||
+        data[id].path.toLowerCase().indexOf(text) >= 0);
+
+      nodes[id].classList.toggle('ml-match', hit);
+    });
+  });
+
+  function element(tag, text) {
+    var e = document.createElement(tag);
+
+    e.textContent = text;
+    return e;
+  }
+  function section(title, lines) {
+    if (lines.length > 0) {
+      var list = document.createElement('ul');
+
+      panel.appendChild(element('h3', title));
+      lines.forEach(function (line) {
+        list.appendChild(element('li', line));
+      });
+      panel.appendChild(list);
+    }
+  }
+  function show(id) {
+    var node = data[id];
+
+    panel.textContent = '';
+    if (!node) {
+      panel.appendChild(element('p', 'Click a type to list its members.'));
+      return;
+    }
+    panel.appendChild(element('h2', node.kind + ' ' + node.name));
+    panel.appendChild(element('p', node.path));
+    if (node.url) {
+      var link = element('a', 'source');
+
+      link.href = node.url;
+      link.target = '_blank';
+      panel.appendChild(link);
+    }
+    section('Fields', node.fields);
+    section('Methods', node.methods);
+    node.implems.forEach(function (implem) {
+      section('impl ' + implem[0], implem[1]);
+    });
+  }
+
+  // Clicking a type highlights its edges and lists its members, instead of following its link.
+  function select(id) {
+    Object.keys(nodes).forEach(function (other) {
+      nodes[other].classList.toggle('ml-selected', other === id);
+    });
+    edges.forEach(function (edge) {
+      edge.g.classList.toggle('ml-outgoing', id !== null && edge.source === id);
+      edge.g.classList.toggle('ml-incoming', id !== null && edge.target === id);
+    });
+    show(id);
+  }
+  svg.addEventListener('click', function (e) {
+    var g = e.target.closest ? e.target.closest('g.node') : null;
+
+    e.preventDefault();
+    if (!moved) {
+      select(g ? g.getAttribute('data-id') : null);
+    }
+  });
+  show(null);
+})();
+"#;
+
+/// The structure `Entry` is what the side panel knows of a type.
+#[derive(Debug, Serialize)]
+struct Entry<'a> {
+    name: &'a str,
+    path: String,
+    kind: &'static str,
+    url: Option<String>,
+    fields: Vec<String>,
+    methods: Vec<String>,
+    /// The trait's path and the methods of each implementation.
+    implems: Vec<(String, Vec<String>)>,
+}
+
+/// The function `entry` returns the members of `node` for the side panel.
+fn entry<'a>(node: &'a Node, config: &Config) -> Entry<'a> {
+    Entry {
+        name: node.name(),
+        path: node
+            .path
+            .iter()
+            .filter(|segment| !segment.is_empty())
+            .map(String::as_str)
+            .collect::<Vec<&str>>()
+            .join("::"),
+        kind: node.kind.as_str(),
+        url: url(node, config),
+        fields: match node.kind {
+            Kind::Struct => node
+                .fields
+                .iter()
+                .map(|field| match field.name {
+                    Some(ref name) => format!("{}{}: {}", field.vis.keyword(), name, field.ty),
+                    None => format!("{}{}", field.vis.keyword(), field.ty),
+                })
+                .collect::<Vec<String>>(),
+            Kind::Enum => node
+                .variants
+                .iter()
+                .map(|variant| match variant.fields.is_empty() {
+                    true => variant.name.clone(),
+                    false => format!("{}({})", variant.name, variant.fields.join(", ")),
+                })
+                .collect::<Vec<String>>(),
+            Kind::Trait => node.items.iter().map(Function::declaration).collect::<Vec<String>>(),
+        },
+        methods: node.methods.iter().map(Function::declaration).collect::<Vec<String>>(),
+        implems: node
+            .implems
+            .iter()
+            .map(|implem| {
+                (
+                    implem.path.join("::"),
+                    implem.methods.iter().map(Function::declaration).collect::<Vec<String>>(),
+                )
+            })
+            .collect::<Vec<(String, Vec<String>)>>(),
+    }
+}
+
+/// The function `render` writes the HTML page of `model` to `w`, around its structured
+/// vector graphics `svg` (from either backend).
+pub fn render<W: Write>(model: &Model, config: &Config, svg: &[u8], w: &mut W) -> io::Result<()> {
+    let entries: BTreeMap<String, Entry> = model
+        .nodes
+        .iter()
+        .map(|node| (node.id(), entry(node, config)))
+        .collect::<BTreeMap<String, Entry>>();
+    let data: String = serde_json::to_string(&entries).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let content: String = String::from_utf8_lossy(svg).into_owned();
+    // The XML declaration, doctype and comments of the file before the `<svg>` element are
+    // not allowed inside a page.
+    let svg: &str = content.find("<svg").map(|at| &content[at..]).unwrap_or(&content);
+
+    writeln!(w, "<!DOCTYPE html>")?;
+    writeln!(w, "<html>")?;
+    writeln!(w, "<head>")?;
+    writeln!(w, "<meta charset=\"utf-8\">")?;
+    writeln!(w, "<title>ml</title>")?;
+    writeln!(w, "<style>\n{}</style>", STYLE)?;
+    writeln!(w, "</head>")?;
+    writeln!(w, "<body>")?;
+    writeln!(w, "<div id=\"ml-main\">")?;
+    writeln!(
+        w,
+        "<div id=\"ml-bar\"><input id=\"ml-search\" type=\"search\" placeholder=\"Search a type\"></div>"
+    )?;
+    writeln!(w, "<div id=\"ml-canvas\">\n{}</div>", svg.trim())?;
+    writeln!(w, "</div>")?;
+    writeln!(w, "<div id=\"ml-panel\"></div>")?;
+    // `</` would close the script element early.
+    writeln!(
+        w,
+        "<script type=\"application/json\" id=\"ml-data\">{}</script>",
+        data.replace("</", "<\\/")
+    )?;
+    writeln!(w, "<script>\n{}</script>", SCRIPT)?;
+    writeln!(w, "</body>")?;
+    writeln!(w, "</html>")
+}
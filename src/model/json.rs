@@ -0,0 +1,105 @@
+//! The purpose of this module is to export and import a model as versioned JSON.
+
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+use super::Model;
+
+/// The version of the JSON format of the model, increased at each incompatible change.
+pub const VERSION: u32 = 1;
+
+/// The structure `Export` is the JSON document of a model.
+#[derive(Debug, Serialize)]
+struct Export<'a> {
+    version: u32,
+    #[serde(flatten)]
+    model: &'a Model,
+}
+
+impl Model {
+    /// The method `to_json` returns the model as a versioned JSON document.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(&Export {
+            version: VERSION,
+            model: self,
+        })
+        .map_err(|e| Error::Model(e.to_string()))
+    }
+
+    /// The method `write_json` writes the model as a versioned JSON document to `writer`.
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<()> {
+        serde_json::to_writer_pretty(
+            writer,
+            &Export {
+                version: VERSION,
+                model: self,
+            },
+        )
+        .map_err(|e| Error::Model(e.to_string()))
+    }
+
+    /// The function `from_json` returns the model of a JSON document, which must not have
+    /// been written by a newer version of the format.
+    pub fn from_json(content: &str) -> Result<Model> {
+        Model::from_value(serde_json::from_str(content).map_err(|e| Error::Model(e.to_string()))?)
+    }
+
+    /// The function `read_json` returns the model of the JSON document read from `reader`.
+    pub fn read_json<R: Read>(reader: R) -> Result<Model> {
+        Model::from_value(serde_json::from_reader(reader).map_err(|e| Error::Model(e.to_string()))?)
+    }
+
+    /// The function `from_value` checks the version of the document before reading its model.
+    fn from_value(value: serde_json::Value) -> Result<Model> {
+        match value.get("version").and_then(serde_json::Value::as_u64) {
+            Some(version) if version > 0 && version <= VERSION as u64 => Model::deserialize(value)
+                .map_err(|e| Error::Model(e.to_string()))
+                .and_then(Model::validate),
+            Some(version) => Err(Error::Model(format!("unsupported version {}", version))),
+            None => Err(Error::Model("missing version".to_string())),
+        }
+    }
+
+    /// The function `validate` returns the model of a document unless a type, a relation or
+    /// a stub has an empty path, which a hand-written or older document can hold.
+    fn validate(model: Model) -> Result<Model> {
+        if let Some(at) = model.nodes.iter().position(|node| node.path.is_empty()) {
+            return Err(Error::Model(format!("node {} has an empty path", at)));
+        }
+        if let Some(at) = model
+            .edges
+            .iter()
+            .position(|edge| edge.source.is_empty() || edge.target.is_empty())
+        {
+            return Err(Error::Model(format!("edge {} has an empty path", at)));
+        }
+        if let Some(at) = model.stubs.iter().position(Vec::is_empty) {
+            return Err(Error::Model(format!("stub {} has an empty path", at)));
+        }
+        Ok(model)
+    }
+
+    /// The method `merge` adds the modules, types and relations of `other` which aren't
+    /// already in the model, like the models of the crates extracted on different machines.
+    pub fn merge(&mut self, other: Model) {
+        for package in other.packages {
+            if !self.packages.iter().any(|known| known.path == package.path) {
+                self.packages.push(package);
+            }
+        }
+        for node in other.nodes {
+            if self.node(&node.path).is_none() {
+                self.nodes.push(node);
+            }
+        }
+        for edge in other.edges {
+            // a relation between the same types can differ, from another version of a crate.
+            if !self.edges.contains(&edge) {
+                self.edges.push(edge);
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! The purpose of this module is to place the nodes of a model without graphviz, with a
+//! layered (Sugiyama-style) layout: the referenced types above the types which hold them.
+
+use super::Model;
+
+/// The horizontal space between two nodes of a layer.
+const GAP_X: f64 = 40.0;
+/// The vertical space between two layers.
+const GAP_Y: f64 = 60.0;
+/// The number of sweeps which order the nodes of the layers.
+const SWEEPS: usize = 8;
+
+/// The structure `Rect` is the position of the top-left corner and the size of a node.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// The method `center` returns the center of the node.
+    pub fn center(&self) -> (f64, f64) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+}
+
+/// The function `links` returns the relations of the model as pairs of indexes of nodes,
+/// from the referenced type to the holder.
+pub fn links(model: &Model) -> Vec<(usize, usize)> {
+    model
+        .relations()
+        .filter_map(|edge| {
+            let source: usize = model.nodes.iter().position(|node| node.path == edge.source)?;
+            let target: usize = model.nodes.iter().position(|node| node.path == edge.target)?;
+
+            Some((source, target))
+        })
+        .filter(|&(source, target)| source != target)
+        .collect::<Vec<(usize, usize)>>()
+}
+
+/// The function `acyclic` returns the links without the ones which close a cycle, found
+/// by a depth-first search.
+fn acyclic(count: usize, links: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    // 0: unvisited, 1: on the stack, 2: done.
+    let mut state: Vec<u8> = vec![0; count];
+    let mut back: Vec<(usize, usize)> = Vec::new();
+
+    for root in 0..count {
+        if state[root] != 0 {
+            continue;
+        }
+        let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+
+        state[root] = 1;
+        while let Some(&mut (node, ref mut next)) = stack.last_mut() {
+            match links.iter().filter(|&&(source, _)| source == node).nth(*next) {
+                Some(&(_, target)) => {
+                    *next += 1;
+                    match state[target] {
+                        0 => {
+                            state[target] = 1;
+                            stack.push((target, 0));
+                        }
+                        1 => back.push((node, target)),
+                        _ => {}
+                    }
+                }
+                None => {
+                    state[node] = 2;
+                    stack.pop();
+                }
+            }
+        }
+    }
+    links
+        .iter()
+        .filter(|link| !back.contains(link))
+        .cloned()
+        .collect::<Vec<(usize, usize)>>()
+}
+
+/// The function `layers` returns the layer of each node: the longest path from a node
+/// without referenced type.
+pub fn layers(count: usize, links: &[(usize, usize)]) -> Vec<usize> {
+    let links: Vec<(usize, usize)> = acyclic(count, links);
+    let mut layer: Vec<usize> = vec![0; count];
+    let mut changed: bool = true;
+
+    // The graph being acyclic, the layers are stable after at most `count` passes.
+    while changed {
+        changed = false;
+        for &(source, target) in links.iter() {
+            if layer[target] < layer[source] + 1 {
+                layer[target] = layer[source] + 1;
+                changed = true;
+            }
+        }
+    }
+    layer
+}
+
+/// The function `order` returns the nodes of each layer, ordered by the barycenter of their
+/// neighbours in the previous and next layers to reduce the crossings.
+pub fn order(layer: &[usize], links: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let depth: usize = layer.iter().max().map_or(0, |max| max + 1);
+    let mut rows: Vec<Vec<usize>> = vec![Vec::new(); depth];
+
+    for (node, &at) in layer.iter().enumerate() {
+        rows[at].push(node);
+    }
+    for sweep in 0..SWEEPS {
+        let downward: bool = sweep % 2 == 0;
+        let range: Vec<usize> = match downward {
+            true => (1..depth).collect::<Vec<usize>>(),
+            false => (0..depth.saturating_sub(1)).rev().collect::<Vec<usize>>(),
+        };
+
+        for at in range {
+            let fixed: &Vec<usize> = if downward { &rows[at - 1] } else { &rows[at + 1] };
+            let position = |node: usize| fixed.iter().position(|&other| other == node);
+            let mut barycenters: Vec<(f64, usize)> = rows[at]
+                .iter()
+                .enumerate()
+                .map(|(current, &node)| {
+                    let neighbours: Vec<usize> = links
+                        .iter()
+                        .filter_map(|&(source, target)| match (source == node, target == node) {
+                            (true, _) => position(target),
+                            (_, true) => position(source),
+                            _ => None,
+                        })
+                        .collect::<Vec<usize>>();
+
+                    match neighbours.is_empty() {
+                        true => (current as f64, node),
+                        false => (
+                            neighbours.iter().sum::<usize>() as f64 / neighbours.len() as f64,
+                            node,
+                        ),
+                    }
+                })
+                .collect::<Vec<(f64, usize)>>();
+
+            barycenters.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+            rows[at] = barycenters.into_iter().map(|(_, node)| node).collect::<Vec<usize>>();
+        }
+    }
+    rows
+}
+
+/// The function `layered` returns the position of each node of `model`, whose sizes
+/// (width, height) are `sizes`, with the top-left corner at the origin.
+pub fn layered(model: &Model, sizes: &[(f64, f64)]) -> Vec<Rect> {
+    let links: Vec<(usize, usize)> = links(model);
+    let layer: Vec<usize> = layers(model.nodes.len(), &links);
+    let rows: Vec<Vec<usize>> = order(&layer, &links);
+    let widths: Vec<f64> = rows
+        .iter()
+        .map(|row| row.iter().map(|&node| sizes[node].0).sum::<f64>() + GAP_X * row.len().saturating_sub(1) as f64)
+        .collect::<Vec<f64>>();
+    let width: f64 = widths.iter().cloned().fold(0.0, f64::max);
+    let mut rects: Vec<Rect> = vec![Rect::default(); model.nodes.len()];
+    let mut y: f64 = 0.0;
+
+    for (row, row_width) in rows.iter().zip(widths.iter()) {
+        // Each layer is centered under the widest one.
+        let mut x: f64 = (width - row_width) / 2.0;
+        let height: f64 = row.iter().map(|&node| sizes[node].1).fold(0.0, f64::max);
+
+        for &node in row.iter() {
+            rects[node] = Rect {
+                x: x,
+                y: y,
+                width: sizes[node].0,
+                height: sizes[node].1,
+            };
+            x += sizes[node].0 + GAP_X;
+        }
+        y += height + GAP_Y;
+    }
+    rects
+}
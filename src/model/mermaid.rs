@@ -0,0 +1,118 @@
+//! The purpose of this module is to render a model as a *Mermaid* `classDiagram`, which
+//! GitHub and GitLab display in markdown.
+
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::core::path2id;
+use crate::Config;
+
+use super::{Edge, Function, Kind, Model, Node};
+
+/// The function `escape` returns `text` with the generics written like *Mermaid* does
+/// (`Vec~T~`) and without the characters which close a class's body.
+fn escape(text: &str) -> String {
+    text.chars()
+        .filter_map(|at: char| match at {
+            '<' | '>' => Some('~'),
+            '{' => Some('('),
+            '}' => Some(')'),
+            '"' | '`' => None,
+            at => Some(at),
+        })
+        .collect::<String>()
+}
+
+/// The function `function2string` returns the member of the method `function`, its return
+/// type after a space like *Mermaid* writes it.
+fn function2string(function: &Function) -> String {
+    match function.output {
+        Some(ref output) => format!("{}{} {}", function.vis.marker(), escape(&function.signature()), escape(output)),
+        None => format!("{}{}", function.vis.marker(), escape(&function.signature())),
+    }
+}
+
+/// The function `node2string` returns the class of `node` with its annotation and members.
+fn node2string(node: &Node, config: &Config) -> String {
+    // the parameters follow the identifier like *Mermaid* writes them (`List~T~`), the label
+    // naming the type without its module.
+    let generics: String = match node.params.is_empty() {
+        true => "".to_string(),
+        false => format!("~{}~", node.params.join(", ")),
+    };
+    let mut members: Vec<String> = Vec::new();
+
+    match node.kind {
+        Kind::Struct => {}
+        Kind::Enum => members.push("<<enumeration>>".to_string()),
+        Kind::Trait => members.push("<<interface>>".to_string()),
+    }
+    if config.include_fields {
+        members.extend(node.fields.iter().enumerate().map(|(at, field)| escape(&field.member(at))));
+        members.extend(node.variants.iter().map(|variant| match variant.fields.is_empty() {
+            true => variant.name.clone(),
+            false => format!("{}: {}", variant.name, escape(&variant.fields.join(", "))),
+        }));
+    }
+    if config.include_methods {
+        members.extend(node.items.iter().map(function2string));
+        members.extend(node.methods.iter().map(function2string));
+    }
+    if config.include_implems {
+        members.extend(
+            node.implems
+                .iter()
+                .flat_map(|implem| implem.methods.iter())
+                .map(function2string),
+        );
+    }
+
+    let mut lines: String = format!(
+        "    class {id}{generics}[\"{name}\"]",
+        id = node.id(),
+        generics = generics,
+        name = node.name()
+    );
+
+    if !members.is_empty() {
+        lines.push_str(" {\n");
+        for member in members {
+            lines.push_str(&format!("        {}\n", member));
+        }
+        lines.push_str("    }");
+    }
+    lines
+}
+
+/// The function `edge2string` returns the arrow of `edge`, from the holder to the
+/// referenced type.
+fn edge2string(edge: &Edge) -> String {
+    let arrow: &str = match (edge.relation, edge.reverse) {
+        (Relation::Association, Relation::Association) => "--",
+        (Relation::Composition, _) => "*--",
+        (Relation::Aggregation, _) => "o--",
+        (Relation::Realization, _) => "..|>",
+        (Relation::Dependency, _) => "..>",
+        (Relation::Association, _) => "-->",
+        (Relation::None, _) => "..",
+    };
+
+    format!(
+        "    {target} {arrow} {source}",
+        target = path2id(&edge.target),
+        arrow = arrow,
+        source = path2id(&edge.source)
+    )
+}
+
+/// The function `render` writes the *Mermaid* `classDiagram` of `model` to `w`.
+pub fn render<W: Write>(model: &Model, config: &Config, w: &mut W) -> io::Result<()> {
+    writeln!(w, "classDiagram")?;
+    for node in model.nodes.iter() {
+        writeln!(w, "{}", node2string(node, config))?;
+    }
+    for edge in model.relations() {
+        writeln!(w, "{}", edge2string(edge))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,567 @@
+//! The purpose of this module is to provide an owned model of the parsed crates, which
+//! outlives the rustc session to be queried, serialized or rendered by other backends.
+
+pub mod d2;
+pub mod diff;
+pub mod dot;
+pub mod drawio;
+pub mod filter;
+pub mod focus;
+pub mod graphml;
+pub mod html;
+pub mod json;
+pub mod layout;
+pub mod mermaid;
+pub mod package;
+pub mod plantuml;
+pub mod public;
+pub mod structurizr;
+pub mod svg;
+pub mod text;
+pub mod xmi;
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use rustc_ast::{ast, ptr};
+use rustc_ast_pretty::pprust::path_to_string;
+use rustc_span::source_map::SourceMap;
+use serde::{Deserialize, Serialize};
+
+use crate::core::item::relation::Relation;
+use crate::core::item::state::abstraction::Abstract;
+use crate::core::item::ItemState;
+use crate::core::segment::Segment;
+use crate::core::{path2id, ListItem};
+use crate::dot::GraphWalk;
+use crate::module::path::{Import, ModulePath};
+use crate::module::Module;
+use crate::Config;
+
+/// The enumeration `Kind` is the kind of a type of the model.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Struct,
+    Enum,
+    Trait,
+}
+
+impl Kind {
+    /// The method `as_str` returns the keyword of the kind, as it's serialized.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            &Kind::Struct => "struct",
+            &Kind::Enum => "enum",
+            &Kind::Trait => "trait",
+        }
+    }
+}
+
+/// The enumeration `Visibility` is the visibility of a type or of a member.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// `pub`.
+    Public,
+    /// `pub(crate)`.
+    Crate,
+    /// `pub(in path)`, `pub(super)`, `pub(self)`, with the path.
+    Restricted(String),
+    /// No visibility, private to its module.
+    Inherited,
+}
+
+impl<'a> From<&'a ast::VisibilityKind> for Visibility {
+    fn from(vis: &'a ast::VisibilityKind) -> Visibility {
+        match vis {
+            &ast::VisibilityKind::Public => Visibility::Public,
+            &ast::VisibilityKind::Restricted { ref path, .. } => match path_to_string(path).as_str() {
+                "crate" => Visibility::Crate,
+                path => Visibility::Restricted(path.to_string()),
+            },
+            &ast::VisibilityKind::Inherited => Visibility::Inherited,
+        }
+    }
+}
+
+impl Visibility {
+    /// The method `marker` returns the *UML* visibility marker: `+` public, `~` package
+    /// (`pub(crate)`, `pub(in path)`) and `-` private.
+    pub fn marker(&self) -> &'static str {
+        match self {
+            &Visibility::Public => "+",
+            &Visibility::Crate | &Visibility::Restricted(_) => "~",
+            &Visibility::Inherited => "-",
+        }
+    }
+
+    /// The method `sign` returns the visibility marker of the graphs rendered from the sources,
+    /// which only tell the public members: `+` public and `-` else.
+    pub fn sign(&self) -> &'static str {
+        match self {
+            &Visibility::Public => "+",
+            _ => "-",
+        }
+    }
+
+    /// The method `keyword` returns the visibility as written in the sources, like `pub(crate) `.
+    pub fn keyword(&self) -> String {
+        match self {
+            &Visibility::Public => "pub ".to_string(),
+            &Visibility::Crate => "pub(crate) ".to_string(),
+            &Visibility::Restricted(ref path) => format!("pub(in {}) ", path),
+            &Visibility::Inherited => "".to_string(),
+        }
+    }
+}
+
+/// The structure `Span` is the position (from 1) of a declaration in its source file.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Span {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// The structure `Field` is a field of a structure, unnamed for a tuple structure.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Field {
+    pub vis: Visibility,
+    pub name: Option<String>,
+    pub ty: String,
+}
+
+impl Field {
+    /// The method `member` returns the *UML* attribute of the field, numbered `at` when
+    /// unnamed, like `+radius: f64`.
+    pub fn member(&self, at: usize) -> String {
+        format!(
+            "{}{}: {}",
+            self.vis.marker(),
+            self.name.clone().unwrap_or_else(|| at.to_string()),
+            self.ty
+        )
+    }
+}
+
+/// The structure `Variant` is a variant of an enumeration with the types of its fields.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Variant {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+/// The structure `Function` is a method, a trait's item or the method of a trait's
+/// implementation.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Function {
+    pub vis: Visibility,
+    pub name: String,
+    /// Arguments as written.
+    pub inputs: Vec<String>,
+    pub output: Option<String>,
+}
+
+impl Function {
+    /// The method `signature` returns the name and the inputs of the method, like
+    /// `area(&Self)`.
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, self.inputs.join(", "))
+    }
+
+    /// The method `returns` returns the output type of the method, `()` when it has none.
+    pub fn returns(&self) -> &str {
+        self.output.as_ref().map(String::as_str).unwrap_or("()")
+    }
+
+    /// The method `operation` returns the signature after the *UML* visibility marker, like
+    /// `+area(&Self)`.
+    pub fn operation(&self) -> String {
+        format!("{}{}", self.vis.marker(), self.signature())
+    }
+
+    /// The method `prototype` returns the signature and, after an arrow, the output type when
+    /// there is one, like `area(&Self) -> f64`.
+    pub fn prototype(&self) -> String {
+        match self.output {
+            Some(ref output) => format!("{} -> {}", self.signature(), output),
+            None => self.signature(),
+        }
+    }
+
+    /// The method `declaration` returns the method as declared in the sources, like
+    /// `pub fn area(&Self) -> f64`.
+    pub fn declaration(&self) -> String {
+        format!("{}fn {}", self.vis.keyword(), self.prototype())
+    }
+
+    /// The method `member` returns the *UML* operation of the method, like `+area(&Self): f64`.
+    pub fn member(&self) -> String {
+        match self.output {
+            Some(ref output) => format!("{}: {}", self.operation(), output),
+            None => self.operation(),
+        }
+    }
+}
+
+/// The structure `Implem` is the implementation of a trait by a type.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Implem {
+    /// Path of the trait as written.
+    pub path: Vec<String>,
+    pub methods: Vec<Function>,
+}
+
+/// The structure `Node` is a type (structure, enumeration or trait) with its members.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Node {
+    /// Crate's tag, module's segments and name of the type (see `ItemState::as_path`).
+    pub path: Vec<String>,
+    pub kind: Kind,
+    pub vis: Visibility,
+    /// Generic parameters.
+    pub params: Vec<String>,
+    pub fields: Vec<Field>,
+    pub variants: Vec<Variant>,
+    /// Items declared by a trait.
+    pub items: Vec<Function>,
+    pub methods: Vec<Function>,
+    pub implems: Vec<Implem>,
+    pub span: Option<Span>,
+}
+
+impl Node {
+    /// The method `name` returns the name of the type.
+    pub fn name(&self) -> &str {
+        self.path.last().map(String::as_str).unwrap_or_default()
+    }
+
+    /// The method `krate` returns the tag of the crate which declares the type (empty when unknown).
+    pub fn krate(&self) -> &str {
+        self.path.first().map(String::as_str).unwrap_or_default()
+    }
+
+    /// The method `module` returns the segments of the module which declares the type,
+    /// from the crate root.
+    pub fn module(&self) -> &[String] {
+        match self.path.len() {
+            0 | 1 => &[],
+            len => &self.path[1..len - 1],
+        }
+    }
+
+    /// The method `id` returns the identifier of the node, the same as the dot's one.
+    pub fn id(&self) -> String {
+        path2id(&self.path)
+    }
+}
+
+/// The structure `Edge` is the relation between two types: `relation` is the relationship
+/// of `source` (the referenced type) with `target`, and `reverse` the one of `target`
+/// with `source`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Edge {
+    pub source: Vec<String>,
+    pub target: Vec<String>,
+    pub relation: Relation,
+    pub reverse: Relation,
+}
+
+/// The structure `Package` is a module of the module tree.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Package {
+    /// Crate's tag and segments of the module from the crate root.
+    pub path: Vec<String>,
+    /// Source file which declares the module's items.
+    pub file: PathBuf,
+    pub imports: Vec<Import>,
+    /// Declared `pub` by its parent module, as a crate root is.
+    #[serde(default = "public")]
+    pub public: bool,
+}
+
+/// The function `public` returns the visibility of the modules of a model exported before
+/// it was recorded, every module being kept in the public API.
+fn public() -> bool {
+    true
+}
+
+impl Package {
+    /// The method `scope` returns the module path of the package, which resolves the paths
+    /// written in the module (see `ModulePath::resolve`).
+    pub fn scope(&self) -> ModulePath {
+        ModulePath {
+            krate: self.path.first().cloned().unwrap_or_default(),
+            path: self
+                .path
+                .iter()
+                .skip(1)
+                .map(OsString::from)
+                .collect::<Vec<OsString>>(),
+            file: self.file.clone(),
+            imports: self.imports.clone(),
+            public: self.public,
+        }
+    }
+}
+
+/// The structure `Model` is the owned description of the parsed crates: the module tree,
+/// the types and their relations.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Model {
+    pub packages: Vec<Package>,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+    /// Types cut off by a focus (see `Model::focus`), drawn as stubs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stubs: Vec<Vec<String>>,
+}
+
+impl Model {
+    /// The method `node` returns the type of path `path`.
+    pub fn node(&self, path: &[String]) -> Option<&Node> {
+        self.nodes.iter().find(|node| node.path.as_slice() == path)
+    }
+
+    /// The method `relations` returns the relations between the types of the model, skipping
+    /// the ones whose types were left out (a filtered or merged model).
+    pub fn relations<'a>(&'a self) -> impl Iterator<Item = &'a Edge> + 'a {
+        self.edges
+            .iter()
+            .filter(move |edge| self.node(&edge.source).is_some() && self.node(&edge.target).is_some())
+    }
+
+    /// The method `submodules` returns the modules (crate's tag and segments) directly below
+    /// the module `path` which hold types, the crate's tags being below `&[]`.
+    pub fn submodules(&self, path: &[String]) -> Vec<&[String]> {
+        let mut children: Vec<&[String]> = self
+            .nodes
+            .iter()
+            .map(|node| &node.path[..node.path.len() - 1])
+            .filter(|owner| owner.len() > path.len() && owner.starts_with(path))
+            .map(|owner| &owner[..path.len() + 1])
+            .collect::<Vec<&[String]>>();
+
+        children.sort();
+        children.dedup();
+        children
+    }
+
+    /// The method `types_of` returns the types declared by the module `path`.
+    pub fn types_of<'a>(&'a self, path: &'a [String]) -> impl Iterator<Item = &'a Node> + 'a {
+        self.nodes
+            .iter()
+            .filter(move |node| &node.path[..node.path.len() - 1] == path)
+    }
+
+    /// The method `krates` returns the tags of the crates of the model, sorted.
+    pub fn krates(&self) -> Vec<&str> {
+        let mut krates: Vec<&str> = self
+            .packages
+            .iter()
+            .map(|package| &package.path)
+            .chain(self.nodes.iter().map(|node| &node.path))
+            .filter_map(|path| path.first().map(String::as_str))
+            .collect::<Vec<&str>>();
+
+        krates.sort();
+        krates.dedup();
+        krates
+    }
+
+    /// The method `of_krate` returns the modules, types and relations of the crate of tag
+    /// `krate`.
+    pub fn of_krate(&self, krate: &str) -> Model {
+        let inside = |path: &[String]| path.first().map(String::as_str) == Some(krate);
+
+        Model {
+            packages: self
+                .packages
+                .iter()
+                .filter(|package| inside(&package.path))
+                .cloned()
+                .collect::<Vec<Package>>(),
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|node| inside(&node.path))
+                .cloned()
+                .collect::<Vec<Node>>(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|edge| inside(&edge.source) && inside(&edge.target))
+                .cloned()
+                .collect::<Vec<Edge>>(),
+            stubs: self
+                .stubs
+                .iter()
+                .filter(|path| inside(path))
+                .cloned()
+                .collect::<Vec<Vec<String>>>(),
+        }
+    }
+
+    /// The method `edges_of` returns the relations from or to the type of path `path`.
+    pub fn edges_of<'a>(&'a self, path: &'a [String]) -> impl Iterator<Item = &'a Edge> + 'a {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.source.as_slice() == path || edge.target.as_slice() == path)
+    }
+}
+
+impl<'a> From<(&'a ItemState<'a>, &'a SourceMap)> for Node {
+    fn from((state, source_map): (&'a ItemState<'a>, &'a SourceMap)) -> Node {
+        let methods: Vec<Function> = state
+            .method
+            .iter()
+            .flat_map(|method| method.func.iter())
+            .map(|&(ref vis, ref name, ref inputs, ref output)| Function {
+                vis: Visibility::from(vis),
+                name: name.to_string(),
+                inputs: inputs.clone(),
+                output: output.clone(),
+            })
+            .collect::<Vec<Function>>();
+        let implems: Vec<Implem> = state
+            .implem
+            .iter()
+            .map(|implem| Implem {
+                path: implem.ty.iter().map(|&(ref name, _)| name.to_string()).collect::<Vec<String>>(),
+                methods: implem
+                    .method
+                    .iter()
+                    .map(|&(ref name, ref inputs, ref output)| Function {
+                        // the methods of a trait's implementation are public with the trait.
+                        vis: Visibility::Public,
+                        name: name.to_string(),
+                        inputs: inputs.clone(),
+                        output: output.clone(),
+                    })
+                    .collect::<Vec<Function>>(),
+            })
+            .collect::<Vec<Implem>>();
+        let span: Option<Span> = match (state.node.path(), state.node.span()) {
+            (Some(path), Some(span)) => {
+                let loc: rustc_span::Loc = source_map.lookup_char_pos(span.lo());
+
+                Some(Span {
+                    file: path.file.clone(),
+                    line: loc.line,
+                    column: loc.col.0 + 1,
+                })
+            }
+            _ => None,
+        };
+        let mut node: Node = Node {
+            path: state.as_path().unwrap_or_default(),
+            kind: Kind::Struct,
+            vis: Visibility::Inherited,
+            params: Vec::new(),
+            fields: Vec::new(),
+            variants: Vec::new(),
+            items: Vec::new(),
+            methods: methods,
+            implems: implems,
+            span: span,
+        };
+
+        match &state.node {
+            &Abstract::Struct(ref item) => {
+                node.vis = Visibility::from(item.vis);
+                node.params = item.params.iter().map(|param| param.to_string()).collect::<Vec<String>>();
+                node.fields = item
+                    .fields
+                    .iter()
+                    .map(|&(vis, ref name, ref ty)| Field {
+                        vis: Visibility::from(vis),
+                        name: name.map(|name| name.to_string()),
+                        ty: ty.clone(),
+                    })
+                    .collect::<Vec<Field>>();
+            }
+            &Abstract::Enum(ref item) => {
+                node.kind = Kind::Enum;
+                node.vis = Visibility::from(item.vis);
+                node.params = item.params.iter().map(|param| param.to_string()).collect::<Vec<String>>();
+                node.variants = item
+                    .variants
+                    .iter()
+                    .map(|&(ref name, ref fields)| Variant {
+                        name: name.to_string(),
+                        fields: fields.clone(),
+                    })
+                    .collect::<Vec<Variant>>();
+            }
+            &Abstract::Trait(ref item) => {
+                node.kind = Kind::Trait;
+                node.vis = Visibility::from(item.vis);
+                node.params = item.params.iter().map(|param| param.to_string()).collect::<Vec<String>>();
+                node.items = item
+                    .items
+                    .iter()
+                    .map(|&(ref name, ref inputs, ref output)| Function {
+                        // the items of a trait are public with it.
+                        vis: Visibility::Public,
+                        name: name.to_string(),
+                        inputs: inputs.clone(),
+                        output: Some(output.clone()),
+                    })
+                    .collect::<Vec<Function>>();
+            }
+            &Abstract::None => {}
+        }
+        node
+    }
+}
+
+impl<'a> From<&'a Segment<'a>> for Edge {
+    fn from(seg: &'a Segment<'a>) -> Edge {
+        Edge {
+            source: seg.left.as_path().unwrap_or_default(),
+            target: seg.right.as_path().unwrap_or_default(),
+            relation: seg.left.as_arrow(&seg.right),
+            reverse: seg.right.as_arrow(&seg.left),
+        }
+    }
+}
+
+impl<'a> From<(Vec<Module>, &'a SourceMap)> for Model {
+    /// The constructor method `from` returns the model of the modules, whose spans
+    /// are looked up in `source_map`.
+    fn from((modules, source_map): (Vec<Module>, &'a SourceMap)) -> Model {
+        let packages: Vec<Package> = modules
+            .iter()
+            .flat_map(|module| module.paths())
+            .map(|path: ModulePath| Package {
+                path: path.segments(),
+                file: path.file,
+                imports: path.imports,
+                public: path.public,
+            })
+            .collect::<Vec<Package>>();
+        let itt: Vec<(ptr::P<ast::Item>, Rc<ModulePath>)> = modules
+            .into_iter()
+            .flat_map(|s: Module| s.into_iter())
+            .collect::<Vec<(ptr::P<ast::Item>, Rc<ModulePath>)>>();
+        let config: Config = Config::default();
+        let it: ListItem = ListItem::from((itt.as_slice().into_iter().peekable(), &config));
+
+        Model {
+            packages: packages,
+            nodes: it
+                .nodes()
+                .iter()
+                .map(|state: &ItemState| Node::from((state, source_map)))
+                .collect::<Vec<Node>>(),
+            edges: it
+                .edges()
+                .iter()
+                .map(|seg: &Segment| Edge::from(seg))
+                .collect::<Vec<Edge>>(),
+            stubs: Vec::new(),
+        }
+    }
+}
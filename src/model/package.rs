@@ -0,0 +1,176 @@
+//! The purpose of this module is to write the package diagram of a model for *Graphviz/Dot*:
+//! one node by module, nested in the clusters of its parents, and the dependencies between
+//! the modules weighted by their number of references.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::core::path2id;
+use crate::dot::escape_html;
+use crate::module::path::{Import, ModulePath};
+use crate::Config;
+
+use super::{Model, Package};
+
+/// The function `id` returns the identifier of the module `path`.
+fn id(path: &[String]) -> String {
+    match path2id(path).as_str() {
+        "nd" => "ndcrate".to_string(),
+        id => id.to_string(),
+    }
+}
+
+/// The function `label` returns the name of the module `path`, the crate's tag for a root.
+fn label(path: &[String]) -> &str {
+    match path.last().map(String::as_str) {
+        Some("") | None => "crate",
+        Some(name) => name,
+    }
+}
+
+/// The function `modules` returns the modules of `model`, sorted: the packages and the
+/// modules of the types, with their parents.
+pub fn modules(model: &Model) -> Vec<Vec<String>> {
+    let mut modules: Vec<Vec<String>> = model
+        .packages
+        .iter()
+        .map(|package| package.path.clone())
+        .chain(model.nodes.iter().map(|node| node.path[..node.path.len() - 1].to_vec()))
+        .filter(|path| !path.is_empty())
+        .flat_map(|path| (1..=path.len()).map(move |len| path[..len].to_vec()))
+        .collect::<Vec<Vec<String>>>();
+
+    modules.sort();
+    modules.dedup();
+    modules
+}
+
+/// The function `owner` returns the module of `modules` which declares the element `path`,
+/// the module itself for a module.
+fn owner<'a>(modules: &'a [Vec<String>], path: &[String]) -> Option<&'a Vec<String>> {
+    modules
+        .iter()
+        .filter(|module| path.starts_with(module) && path.len() <= module.len() + 1)
+        .max_by_key(|module| module.len())
+}
+
+/// The function `imported` returns the module imported from by `import` in `package`: the
+/// imported module or the module of the imported element. A path relative to the module
+/// (not `crate`, `self` nor `super`) counts only for a known module or type, the other ones
+/// being external.
+fn imported<'a>(
+    model: &Model,
+    modules: &'a [Vec<String>],
+    package: &Package,
+    import: &Import,
+) -> Option<&'a Vec<String>> {
+    let module: ModulePath = ModulePath {
+        imports: Vec::new(),
+        ..package.scope()
+    };
+    let absolute: bool = match import.path.first().map(String::as_str) {
+        Some("crate") | Some("self") | Some("super") => true,
+        _ => false,
+    };
+
+    module
+        .resolve(&import.path.join("::"))
+        .into_iter()
+        .filter(|candidate| absolute || modules.contains(candidate) || model.node(candidate).is_some())
+        .filter_map(|candidate| owner(modules, &candidate))
+        .next()
+}
+
+/// The function `dependencies` returns the dependencies between the modules of `model`,
+/// from the module which refers to the one referred, with their number of references: the
+/// relations between their types and the imports of their `use` declarations.
+pub fn dependencies(model: &Model, modules: &[Vec<String>]) -> BTreeMap<(Vec<String>, Vec<String>), usize> {
+    let mut dependencies: BTreeMap<(Vec<String>, Vec<String>), usize> = BTreeMap::new();
+
+    for edge in model.relations() {
+        // the holder (target) refers to the referenced type (source).
+        if let (Some(from), Some(to)) = (owner(modules, &edge.target), owner(modules, &edge.source)) {
+            if from != to {
+                *dependencies.entry((from.clone(), to.clone())).or_insert(0) += 1;
+            }
+        }
+    }
+    for package in model.packages.iter() {
+        for import in package.imports.iter() {
+            if let Some(to) = imported(model, modules, package, import) {
+                if &package.path != to {
+                    *dependencies.entry((package.path.clone(), to.clone())).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    dependencies
+}
+
+/// The function `cluster` writes the module `path` and its submodules, in a cluster when it
+/// has submodules.
+fn cluster<W: Write>(w: &mut W, modules: &[Vec<String>], path: &[String], indent: usize) -> io::Result<()> {
+    let children: Vec<&Vec<String>> = modules
+        .iter()
+        .filter(|module| module.len() == path.len() + 1 && module.starts_with(path))
+        .collect::<Vec<&Vec<String>>>();
+    let tab: String = "    ".repeat(indent);
+    let node: String = format!(
+        "{}{}[label=\"{}\"][tooltip=\"{}\"];",
+        tab,
+        id(path),
+        escape_html(label(path)),
+        escape_html(
+            &match path.iter().all(String::is_empty) {
+                true => label(path).to_string(),
+                false => path
+                    .iter()
+                    .filter(|segment| !segment.is_empty())
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>()
+                    .join("::"),
+            }
+        )
+    );
+
+    match children.is_empty() {
+        true => writeln!(w, "{}", node),
+        false => {
+            writeln!(w, "{}subgraph cluster_{} {{", tab, id(path))?;
+            writeln!(w, "{}    label=\"{}\";", tab, escape_html(label(path)))?;
+            writeln!(w, "    {}", node)?;
+            for child in children {
+                cluster(w, modules, child, indent + 1)?;
+            }
+            writeln!(w, "{}}}", tab)
+        }
+    }
+}
+
+/// The function `render` writes the package diagram of `model` for *Graphviz/Dot* to `w`.
+pub fn render<W: Write>(model: &Model, config: &Config, w: &mut W) -> io::Result<()> {
+    let modules: Vec<Vec<String>> = modules(model);
+
+    writeln!(w, "digraph ml {{")?;
+    writeln!(
+        w,
+        "    node[shape=\"folder\"][fontname=\"{}\"];",
+        escape_html(&config.font_name)
+    )?;
+    writeln!(w, "    edge[style=\"dashed\"][arrowhead=\"vee\"];")?;
+    for root in modules.iter().filter(|module| module.len() == 1) {
+        cluster(w, &modules, root, 1)?;
+    }
+    for ((from, to), count) in dependencies(model, &modules) {
+        writeln!(
+            w,
+            "    {} -> {}[label=\"{}\"][weight={}][penwidth={:.1}];",
+            id(&from),
+            id(&to),
+            count,
+            count,
+            1.0 + (count as f64).ln()
+        )?;
+    }
+    writeln!(w, "}}")
+}
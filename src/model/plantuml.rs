@@ -0,0 +1,97 @@
+//! The purpose of this module is to render a model as a *PlantUML* class diagram, which
+//! can be edited by hand.
+
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::core::path2id;
+use crate::Config;
+
+use super::{Edge, Function, Kind, Model, Node};
+
+/// The function `node2string` returns the declaration of the class, enumeration or interface
+/// of `node` with its members.
+fn node2string(node: &Node, config: &Config) -> String {
+    let keyword: &str = match node.kind {
+        Kind::Struct => "class",
+        Kind::Enum => "enum",
+        Kind::Trait => "interface",
+    };
+    let name: String = match node.params.is_empty() {
+        true => node.name().to_string(),
+        false => format!("{}<{}>", node.name(), node.params.join(", ")),
+    };
+    let mut members: Vec<String> = Vec::new();
+
+    if config.include_fields {
+        members.extend(node.fields.iter().enumerate().map(|(at, field)| field.member(at)));
+        members.extend(node.variants.iter().map(|variant| match variant.fields.is_empty() {
+            true => variant.name.clone(),
+            false => format!("{}({})", variant.name, variant.fields.join(", ")),
+        }));
+    }
+    if config.include_methods {
+        members.extend(node.items.iter().map(Function::member));
+        members.extend(node.methods.iter().map(Function::member));
+    }
+    if config.include_implems {
+        members.extend(
+            node.implems
+                .iter()
+                .flat_map(|implem| implem.methods.iter())
+                .map(Function::member),
+        );
+    }
+
+    let mut lines: String = format!(
+        "{keyword} \"{name}\" as {id}",
+        keyword = keyword,
+        name = name,
+        id = node.id()
+    );
+
+    match node.krate() {
+        "" => {}
+        krate => lines.push_str(&format!(" <<{}>>", krate)),
+    }
+    lines.push_str(" {\n");
+    for member in members {
+        lines.push_str(&format!("  {}\n", member));
+    }
+    lines.push_str("}\n");
+    lines
+}
+
+/// The function `edge2string` returns the arrow of `edge`, from the holder to the
+/// referenced type.
+fn edge2string(edge: &Edge) -> String {
+    let arrow: &str = match (edge.relation, edge.reverse) {
+        (Relation::Association, Relation::Association) => "--",
+        (Relation::Composition, _) => "*--",
+        (Relation::Aggregation, _) => "o--",
+        (Relation::Realization, _) => "..|>",
+        (Relation::Dependency, _) => "..>",
+        (Relation::Association, _) => "-->",
+        (Relation::None, _) => "..",
+    };
+
+    format!(
+        "{target} {arrow} {source}\n",
+        target = path2id(&edge.target),
+        arrow = arrow,
+        source = path2id(&edge.source)
+    )
+}
+
+/// The function `render` writes the *PlantUML* class diagram of `model` to `w`.
+pub fn render<W: Write>(model: &Model, config: &Config, w: &mut W) -> io::Result<()> {
+    writeln!(w, "@startuml")?;
+    writeln!(w, "hide empty members")?;
+    for node in model.nodes.iter() {
+        writeln!(w, "{}", node2string(node, config))?;
+    }
+    for edge in model.relations() {
+        write!(w, "{}", edge2string(edge))?;
+    }
+    writeln!(w, "@enduml")
+}
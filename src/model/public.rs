@@ -0,0 +1,200 @@
+//! The purpose of this module is to cut a model down to its public API: the types which the
+//! downstream crates can name, at the path they see them, with their public members.
+
+use std::collections::{HashMap, HashSet};
+
+use super::package::modules;
+use super::{Edge, Field, Function, Model, Node, Package, Visibility};
+
+/// The function `mentions` returns true when the type `ty`, as written, names one of `names`.
+fn mentions(ty: &str, names: &[&str]) -> bool {
+    ty.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .any(|word| names.contains(&word))
+}
+
+/// The function `carries` returns true when a member of `node`, or a trait it implements,
+/// names one of `names`.
+fn carries(node: &Node, names: &[&str]) -> bool {
+    node.fields.iter().any(|field| mentions(&field.ty, names))
+        || node.variants.iter().flat_map(|variant| variant.fields.iter()).any(|ty| mentions(ty, names))
+        || node
+            .items
+            .iter()
+            .chain(node.methods.iter())
+            .chain(node.implems.iter().flat_map(|implem| implem.methods.iter()))
+            .any(|function| {
+                function.inputs.iter().any(|input| mentions(input, names))
+                    || function.output.as_ref().map_or(false, |output| mentions(output, names))
+            })
+        || node.implems.iter().any(|implem| implem.path.iter().any(|segment| names.contains(&segment.as_str())))
+}
+
+/// The function `visible` returns the type `node` with only its public fields and methods.
+fn visible(node: &Node) -> Node {
+    Node {
+        fields: node
+            .fields
+            .iter()
+            .filter(|field| field.vis == Visibility::Public)
+            .cloned()
+            .collect::<Vec<Field>>(),
+        methods: node
+            .methods
+            .iter()
+            .filter(|method| method.vis == Visibility::Public)
+            .cloned()
+            .collect::<Vec<Function>>(),
+        ..node.clone()
+    }
+}
+
+impl Model {
+    /// The method `exports` returns the publicly reachable modules and the path under which
+    /// each type of the public API is seen: its own path for a `pub` type of such a module,
+    /// else the path of a `pub use` of such a module which re-exports it.
+    fn exports(&self) -> (HashSet<Vec<String>>, HashMap<&[String], Vec<String>>) {
+        let modules: Vec<Vec<String>> = modules(self);
+        // a module whose declaration is unknown is kept.
+        let declared = |path: &[String]| -> bool {
+            self.packages
+                .iter()
+                .find(|package| package.path.as_slice() == path)
+                .map_or(true, |package| package.public)
+        };
+        let mut reachable: HashSet<Vec<String>> = modules
+            .iter()
+            .filter(|module| (2..=module.len()).all(|len| declared(&module[..len])))
+            .cloned()
+            .collect::<HashSet<Vec<String>>>();
+        let mut exports: HashMap<&[String], Vec<String>> = HashMap::new();
+
+        // The re-exports can make other modules reachable, until nothing changes.
+        loop {
+            let before: (usize, usize) = (reachable.len(), exports.len());
+            let packages: Vec<&Package> = self
+                .packages
+                .iter()
+                .filter(|package| reachable.contains(&package.path))
+                .collect::<Vec<&Package>>();
+
+            for node in self.nodes.iter().filter(|node| node.vis == Visibility::Public) {
+                if reachable.contains(&node.path[..node.path.len() - 1]) {
+                    exports.entry(&node.path).or_insert_with(|| node.path.clone());
+                }
+            }
+            for package in packages {
+                for import in package.imports.iter().filter(|import| import.public) {
+                    for candidate in package.scope().resolve(&import.path.join("::")) {
+                        match (&import.name, self.node(&candidate)) {
+                            (&Some(ref name), Some(node)) if node.vis == Visibility::Public => {
+                                let mut path: Vec<String> = package.path.clone();
+
+                                path.push(name.clone());
+                                exports.entry(&node.path).or_insert(path);
+                            }
+                            // `pub use module::*` re-exports the public items of the module.
+                            (&None, None) if modules.contains(&candidate) => {
+                                for node in self.nodes.iter().filter(|node| {
+                                    node.vis == Visibility::Public && node.path[..node.path.len() - 1] == candidate[..]
+                                }) {
+                                    let mut path: Vec<String> = package.path.clone();
+
+                                    path.push(node.name().to_string());
+                                    exports.entry(&node.path).or_insert(path);
+                                }
+                                for module in modules.iter().filter(|module| {
+                                    module.len() == candidate.len() + 1
+                                        && module.starts_with(&candidate)
+                                        && declared(module)
+                                }) {
+                                    reachable.insert(module.clone());
+                                }
+                            }
+                            // `pub use module` makes the module reachable.
+                            (&Some(_), None) if modules.contains(&candidate) => {
+                                reachable.insert(candidate);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            if before == (reachable.len(), exports.len()) {
+                return (reachable, exports);
+            }
+        }
+    }
+
+    /// The method `carried` returns false when the relation `edge` only comes from members
+    /// hidden from the public API: one of its types names the other, but none of their
+    /// public members does.
+    fn carried(&self, edge: &Edge, exports: &HashMap<&[String], Vec<String>>) -> bool {
+        match (self.node(&edge.source), self.node(&edge.target)) {
+            (Some(source), Some(target)) => {
+                // the names of a type are the declared one and the exported one.
+                let names = |node: &Node| -> Vec<String> {
+                    let mut names: Vec<String> = vec![node.name().to_string()];
+
+                    if let Some(name) = exports.get(node.path.as_slice()).and_then(|path| path.last()) {
+                        names.push(name.clone());
+                    }
+                    names
+                };
+                let (source_names, target_names): (Vec<String>, Vec<String>) = (names(source), names(target));
+                let source_names: Vec<&str> = source_names.iter().map(String::as_str).collect::<Vec<&str>>();
+                let target_names: Vec<&str> = target_names.iter().map(String::as_str).collect::<Vec<&str>>();
+
+                !(carries(target, &source_names) || carries(source, &target_names))
+                    || carries(&visible(target), &source_names)
+                    || carries(&visible(source), &target_names)
+            }
+            _ => true,
+        }
+    }
+
+    /// The method `public_api` returns what the downstream crates see of the model: the types
+    /// of the public API at the path they are exported under with their public fields and
+    /// methods, their relations, and the publicly reachable modules.
+    pub fn public_api(&self) -> Model {
+        let (reachable, exports): (HashSet<Vec<String>>, HashMap<&[String], Vec<String>>) = self.exports();
+
+        Model {
+            packages: self
+                .packages
+                .iter()
+                .filter(|package| reachable.contains(&package.path))
+                .cloned()
+                .collect::<Vec<Package>>(),
+            nodes: self
+                .nodes
+                .iter()
+                .filter_map(|node| {
+                    exports.get(node.path.as_slice()).map(|path: &Vec<String>| Node {
+                        path: path.clone(),
+                        ..visible(node)
+                    })
+                })
+                .collect::<Vec<Node>>(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|edge| self.carried(edge, &exports))
+                .filter_map(
+                    |edge| match (exports.get(edge.source.as_slice()), exports.get(edge.target.as_slice())) {
+                        (Some(source), Some(target)) => Some(Edge {
+                            source: source.clone(),
+                            target: target.clone(),
+                            ..edge.clone()
+                        }),
+                        _ => None,
+                    },
+                )
+                .collect::<Vec<Edge>>(),
+            stubs: self
+                .stubs
+                .iter()
+                .filter_map(|stub| exports.get(stub.as_slice()).cloned())
+                .collect::<Vec<Vec<String>>>(),
+        }
+    }
+}
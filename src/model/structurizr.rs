@@ -0,0 +1,206 @@
+//! The purpose of this module is to write a model as a *Structurizr DSL* workspace of the
+//! *C4 model*: each crate is a container, each top-level module a component, whose
+//! `properties` can hold the code-level detail.
+
+use std::io::{self, Write};
+
+use crate::core::path2id;
+
+use super::{Edge, Model, Node};
+
+/// The name of the component of the items of the crate root, and of the container of an
+/// unknown crate.
+const ROOT: &'static str = "crate";
+
+/// The function `quote` returns `text` as a quoted DSL string.
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The function `identifier` returns the DSL identifier of the segments `path`, escaped like
+/// the nodes' (see `path2id`).
+fn identifier(prefix: &str, path: &[&str]) -> String {
+    let path: Vec<String> = path.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+
+    format!("{}_{}", prefix, &path2id(&path)[2..])
+}
+
+/// The function `container` returns the name of the container of `node`.
+fn container(node: &Node) -> &str {
+    match node.krate() {
+        "" => ROOT,
+        krate => krate,
+    }
+}
+
+/// The function `component` returns the name of the component (top-level module) of `node`.
+fn component(node: &Node) -> &str {
+    node.module().first().map(String::as_str).unwrap_or(ROOT)
+}
+
+/// The function `name` returns the name of `node` in its crate, like `shape::Circle`.
+fn name(node: &Node) -> String {
+    node.path[1..].join("::")
+}
+
+/// The function `properties` writes the block of `properties` of an element, by key and
+/// value, at `indent`.
+fn properties<W: Write>(w: &mut W, indent: &str, properties: &[(String, &str)]) -> io::Result<()> {
+    writeln!(w, " {{")?;
+    writeln!(w, "{}    properties {{", indent)?;
+    for &(ref key, value) in properties.iter() {
+        writeln!(w, "{}        {} {}", indent, quote(key), quote(value))?;
+    }
+    writeln!(w, "{}    }}", indent)?;
+    write!(w, "{}}}", indent)
+}
+
+/// The function `relation` returns the property of the relation `edge`, from the holder to
+/// the referenced type, and the name of its relationship.
+fn relation(model: &Model, edge: &Edge) -> Option<(String, &'static str)> {
+    match (model.node(&edge.source), model.node(&edge.target)) {
+        (Some(source), Some(target)) => Some((format!("{} -> {}", name(target), name(source)), edge.relation.as_str())),
+        _ => None,
+    }
+}
+
+/// The function `render` writes the *Structurizr DSL* workspace of `model` to `w`, with
+/// the code-level detail when `code` is set: the types and their relations as properties of
+/// the components of their modules and of the relationships between components.
+pub fn render<W: Write>(model: &Model, code: bool, w: &mut W) -> io::Result<()> {
+    let mut containers: Vec<&str> = model.nodes.iter().map(container).collect::<Vec<&str>>();
+
+    containers.sort();
+    containers.dedup();
+    writeln!(w, "workspace {} {{", quote("ml"))?;
+    writeln!(w, "    model {{")?;
+    writeln!(w, "        system = softwareSystem {} {{", quote("ml"))?;
+    for krate in containers.iter() {
+        let nodes: Vec<&Node> = model
+            .nodes
+            .iter()
+            .filter(|node| container(node) == *krate)
+            .collect::<Vec<&Node>>();
+        let mut components: Vec<&str> = nodes.iter().map(|node| component(node)).collect::<Vec<&str>>();
+
+        components.sort();
+        components.dedup();
+        writeln!(
+            w,
+            "            {} = container {} {} {} {{",
+            identifier("container", &[krate]),
+            quote(krate),
+            quote("Rust crate"),
+            quote("Rust")
+        )?;
+        for module in components {
+            let types: Vec<&&Node> = nodes
+                .iter()
+                .filter(|node| component(node) == module)
+                .collect::<Vec<&&Node>>();
+
+            write!(
+                w,
+                "                {} = component {} {} {}",
+                identifier("component", &[krate, module]),
+                quote(module),
+                quote(&match types.len() {
+                    1 => "1 type".to_string(),
+                    len => format!("{} types", len),
+                }),
+                quote("Rust module")
+            )?;
+            if code {
+                // The types of the module, then the relations between them.
+                let mut detail: Vec<(String, &str)> = types
+                    .iter()
+                    .map(|node| (name(node), node.kind.as_str()))
+                    .collect::<Vec<(String, &str)>>();
+
+                detail.extend(
+                    model
+                        .relations()
+                        .filter(|edge| match (model.node(&edge.source), model.node(&edge.target)) {
+                            (Some(source), Some(target)) => {
+                                container(source) == *krate
+                                    && component(source) == module
+                                    && container(target) == *krate
+                                    && component(target) == module
+                            }
+                            _ => false,
+                        })
+                        .filter_map(|edge| relation(model, edge)),
+                );
+                properties(w, "                ", &detail)?;
+            }
+            writeln!(w)?;
+        }
+        writeln!(w, "            }}")?;
+    }
+    writeln!(w, "        }}")?;
+
+    // The relationships between components, from the holder's module to the referenced one,
+    // counted by relationship.
+    let mut uses: Vec<((String, String), Vec<&Edge>)> = Vec::new();
+
+    for edge in model.relations() {
+        if let (Some(source), Some(target)) = (model.node(&edge.source), model.node(&edge.target)) {
+            let link: (String, String) = (
+                identifier("component", &[container(target), component(target)]),
+                identifier("component", &[container(source), component(source)]),
+            );
+
+            if link.0 != link.1 {
+                match uses.iter().position(|&(ref known, _)| known == &link) {
+                    Some(at) => uses[at].1.push(edge),
+                    None => uses.push((link, vec![edge])),
+                }
+            }
+        }
+    }
+    for &((ref from, ref to), ref edges) in uses.iter() {
+        let mut kinds: Vec<&str> = edges.iter().map(|edge| edge.relation.as_str()).collect::<Vec<&str>>();
+
+        kinds.sort();
+        let counts: Vec<String> = kinds
+            .iter()
+            .enumerate()
+            .filter(|&(at, kind)| at == 0 || kinds[at - 1] != *kind)
+            .map(|(_, kind)| format!("{} {}", kinds.iter().filter(|other| *other == kind).count(), kind))
+            .collect::<Vec<String>>();
+
+        write!(
+            w,
+            "        {} -> {} {}",
+            from,
+            to,
+            quote(&match edges.len() {
+                1 => format!("1 relation ({})", counts.join(", ")),
+                len => format!("{} relations ({})", len, counts.join(", ")),
+            })
+        )?;
+        if code {
+            let detail: Vec<(String, &str)> = edges
+                .iter()
+                .filter_map(|edge| relation(model, edge))
+                .collect::<Vec<(String, &str)>>();
+
+            properties(w, "        ", &detail)?;
+        }
+        writeln!(w)?;
+    }
+    writeln!(w, "    }}")?;
+    writeln!(w, "    views {{")?;
+    writeln!(w, "        container system {{")?;
+    writeln!(w, "            include *")?;
+    writeln!(w, "            autoLayout")?;
+    writeln!(w, "        }}")?;
+    for krate in containers.iter() {
+        writeln!(w, "        component {} {{", identifier("container", &[krate]))?;
+        writeln!(w, "            include *")?;
+        writeln!(w, "            autoLayout")?;
+        writeln!(w, "        }}")?;
+    }
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")
+}
@@ -0,0 +1,314 @@
+//! The purpose of this module is to draw a model as structured vector graphics without
+//! graphviz: the labels of the dot graphs are placed by the built-in layered layout.
+
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::core::path2id;
+use crate::dot::escape_html;
+use crate::Config;
+
+use super::dot::{compartments, url};
+use super::layout::{self, Rect};
+use super::{Edge, Model, Node};
+
+/// The size of the font of the labels.
+const FONT_SIZE: f64 = 14.0;
+/// The size of the font of the crate's tag.
+const KRATE_SIZE: f64 = 9.0;
+/// The height of a line of text, by unit of font size.
+const LINE: f64 = 1.3;
+/// The average width of a character, by unit of font size.
+const CHAR: f64 = 0.6;
+/// The space around the text of a cell, like `cellpadding`.
+const PADDING: f64 = 10.0;
+/// The space around the drawing.
+const MARGIN: f64 = 20.0;
+/// The length of an arrowhead.
+const ARROW: f64 = 12.0;
+
+/// The structure `Row` is a cell of a label: its color, lines and font.
+struct Row<'a> {
+    bgcolor: &'a str,
+    lines: Vec<String>,
+    size: f64,
+    bold: bool,
+    /// The crate's tag and the header are centered, the members left-aligned.
+    centered: bool,
+}
+
+impl<'a> Row<'a> {
+    /// The method `height` returns the height of the cell.
+    fn height(&self) -> f64 {
+        self.lines.len().max(1) as f64 * LINE * self.size + 2.0 * PADDING
+    }
+
+    /// The method `width` returns the width of the cell for its longest line.
+    fn width(&self) -> f64 {
+        let weight: f64 = match self.bold {
+            true => 1.1,
+            false => 1.0,
+        };
+
+        self.lines
+            .iter()
+            .map(|line| line.chars().count() as f64 * CHAR * self.size * weight)
+            .fold(0.0, f64::max)
+            + 2.0 * PADDING
+    }
+}
+
+/// The function `rows` returns the cells of the label of `node` like the dot's table:
+/// the crate's tag, the header and the compartments.
+fn rows<'a>(node: &Node, config: &'a Config) -> Vec<Row<'a>> {
+    let (header, compartments) = compartments(node, config);
+    let mut rows: Vec<Row> = Vec::new();
+
+    if !node.krate().is_empty() {
+        rows.push(Row {
+            bgcolor: "white",
+            lines: vec![node.krate().to_string()],
+            size: KRATE_SIZE,
+            bold: false,
+            centered: true,
+        });
+    }
+    rows.push(Row {
+        bgcolor: header,
+        lines: vec![node.name().to_string()],
+        size: FONT_SIZE,
+        bold: true,
+        centered: true,
+    });
+    for (bgcolor, lines) in compartments {
+        rows.push(Row {
+            bgcolor: bgcolor,
+            lines: lines,
+            size: FONT_SIZE,
+            bold: false,
+            centered: false,
+        });
+    }
+    rows
+}
+
+/// The function `size` returns the width and height of the label of `node`.
+pub(crate) fn size(node: &Node, config: &Config) -> (f64, f64) {
+    let rows: Vec<Row> = rows(node, config);
+
+    (
+        rows.iter().map(Row::width).fold(0.0, f64::max),
+        rows.iter().map(Row::height).sum::<f64>(),
+    )
+}
+
+/// The function `label` writes the label of `node` at `rect`.
+fn label<W: Write>(w: &mut W, node: &Node, config: &Config, rect: &Rect) -> io::Result<()> {
+    let url: Option<String> = url(node, config);
+    let mut y: f64 = rect.y;
+
+    writeln!(w, "<g id=\"{}\" class=\"node\">", node.id())?;
+    writeln!(
+        w,
+        "<title>{}</title>",
+        escape_html(
+            &node
+                .path
+                .iter()
+                .filter(|segment| !segment.is_empty())
+                .map(String::as_str)
+                .collect::<Vec<&str>>()
+                .join("::")
+        )
+    )?;
+    if let Some(ref url) = url {
+        writeln!(w, "<a xlink:href=\"{}\">", escape_html(url))?;
+    }
+    for row in rows(node, config) {
+        let height: f64 = row.height();
+        let (x, anchor): (f64, &str) = match row.centered {
+            true => (rect.x + rect.width / 2.0, "middle"),
+            false => (rect.x + PADDING, "start"),
+        };
+
+        writeln!(
+            w,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\" stroke=\"black\"/>",
+            rect.x,
+            y,
+            rect.width,
+            height,
+            escape_html(row.bgcolor)
+        )?;
+        for (at, line) in row.lines.iter().enumerate() {
+            writeln!(
+                w,
+                "<text x=\"{:.1}\" y=\"{:.1}\" font-family=\"{}\" font-size=\"{}\"{} text-anchor=\"{}\">{}</text>",
+                x,
+                y + PADDING + ((at + 1) as f64 * LINE - 0.3) * row.size,
+                escape_html(&config.font_name),
+                row.size,
+                match row.bold {
+                    true => " font-weight=\"bold\"",
+                    false => "",
+                },
+                anchor,
+                escape_html(line)
+            )?;
+        }
+        y += height;
+    }
+    if url.is_some() {
+        writeln!(w, "</a>")?;
+    }
+    writeln!(w, "</g>")
+}
+
+/// The function `clip` returns the point where the segment from the center of `rect` to
+/// `(x, y)` leaves `rect`.
+fn clip(rect: &Rect, (x, y): (f64, f64)) -> (f64, f64) {
+    let (cx, cy): (f64, f64) = rect.center();
+    let (dx, dy): (f64, f64) = (x - cx, y - cy);
+    let scale: f64 = match (dx == 0.0, dy == 0.0) {
+        (true, true) => return (cx, cy),
+        (true, false) => rect.height / 2.0 / dy.abs(),
+        (false, true) => rect.width / 2.0 / dx.abs(),
+        (false, false) => (rect.width / 2.0 / dx.abs()).min(rect.height / 2.0 / dy.abs()),
+    };
+
+    (cx + dx * scale, cy + dy * scale)
+}
+
+/// The function `arrowhead` returns the shape of the arrowhead of `edge` whose tip is `tip`
+/// and direction `(dx, dy)`, like `Relation::as_style`.
+fn arrowhead(edge: &Edge, (x, y): (f64, f64), (dx, dy): (f64, f64)) -> Option<String> {
+    // The point at `back` lengths behind the tip and `side` lengths on its left.
+    let at = |back: f64, side: f64| -> String {
+        format!("{:.1},{:.1}", x - dx * back - dy * side, y - dy * back + dx * side)
+    };
+
+    match (edge.relation, edge.reverse) {
+        (Relation::Association, Relation::Association) | (Relation::None, _) => None,
+        (Relation::Composition, _) | (Relation::Aggregation, _) => Some(format!(
+            "<polygon points=\"{} {} {} {}\" fill=\"{}\" stroke=\"black\"/>",
+            at(0.0, 0.0),
+            at(ARROW, ARROW / 3.0),
+            at(2.0 * ARROW, 0.0),
+            at(ARROW, -ARROW / 3.0),
+            match edge.relation {
+                Relation::Composition => "black",
+                _ => "white",
+            }
+        )),
+        (Relation::Realization, _) => Some(format!(
+            "<polygon points=\"{} {} {}\" fill=\"white\" stroke=\"black\"/>",
+            at(0.0, 0.0),
+            at(ARROW, ARROW / 2.0),
+            at(ARROW, -ARROW / 2.0)
+        )),
+        (Relation::Dependency, _) | (Relation::Association, _) => Some(format!(
+            "<polyline points=\"{} {} {}\" fill=\"none\" stroke=\"black\"/>",
+            at(ARROW, ARROW / 2.0),
+            at(0.0, 0.0),
+            at(ARROW, -ARROW / 2.0)
+        )),
+    }
+}
+
+/// The function `link` writes the edge of `edge` from `source` to `target`, a loop when the
+/// type refers to itself.
+fn link<W: Write>(w: &mut W, at: usize, edge: &Edge, source: &Rect, target: &Rect) -> io::Result<()> {
+    let dash: &str = match edge.relation {
+        Relation::Realization | Relation::Dependency => " stroke-dasharray=\"5,2\"",
+        _ => "",
+    };
+    let (path, tip, direction): (String, (f64, f64), (f64, f64)) = match edge.source == edge.target {
+        true => {
+            let (right, cy): (f64, f64) = (target.x + target.width, target.center().1);
+
+            (
+                format!(
+                    "M{:.1},{:.1} C{:.1},{:.1} {:.1},{:.1} {:.1},{:.1}",
+                    right,
+                    cy - 10.0,
+                    right + 40.0,
+                    cy - 30.0,
+                    right + 40.0,
+                    cy + 30.0,
+                    right,
+                    cy + 10.0
+                ),
+                (right, cy + 10.0),
+                (-1.0, 0.0),
+            )
+        }
+        false => {
+            let from: (f64, f64) = clip(source, target.center());
+            let to: (f64, f64) = clip(target, source.center());
+            let length: f64 = ((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)).sqrt().max(1.0);
+
+            (
+                format!("M{:.1},{:.1} L{:.1},{:.1}", from.0, from.1, to.0, to.1),
+                to,
+                ((to.0 - from.0) / length, (to.1 - from.1) / length),
+            )
+        }
+    };
+
+    writeln!(
+        w,
+        "<g id=\"ed{}\" class=\"edge\" data-source=\"{}\" data-target=\"{}\">",
+        at,
+        path2id(&edge.source),
+        path2id(&edge.target)
+    )?;
+    writeln!(
+        w,
+        "<title>{}&#45;&gt;{}</title>",
+        path2id(&edge.source),
+        path2id(&edge.target)
+    )?;
+    writeln!(w, "<path d=\"{}\" fill=\"none\" stroke=\"black\"{}/>", path, dash)?;
+    if let Some(arrowhead) = arrowhead(edge, tip, direction) {
+        writeln!(w, "{}", arrowhead)?;
+    }
+    writeln!(w, "</g>")
+}
+
+/// The function `render` writes the structured vector graphics of `model` to `w`.
+pub fn render<W: Write>(model: &Model, config: &Config, w: &mut W) -> io::Result<()> {
+    let sizes: Vec<(f64, f64)> = model
+        .nodes
+        .iter()
+        .map(|node| size(node, config))
+        .collect::<Vec<(f64, f64)>>();
+    let rects: Vec<Rect> = layout::layered(model, &sizes);
+    let width: f64 = rects.iter().map(|rect| rect.x + rect.width).fold(0.0, f64::max) + 2.0 * MARGIN;
+    let height: f64 = rects.iter().map(|rect| rect.y + rect.height).fold(0.0, f64::max) + 2.0 * MARGIN;
+
+    writeln!(w, "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>")?;
+    writeln!(
+        w,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" width=\"{:.0}pt\" height=\"{:.0}pt\" viewBox=\"0.00 0.00 {:.2} {:.2}\">",
+        width, height, width, height
+    )?;
+    writeln!(
+        w,
+        "<g id=\"graph0\" class=\"graph\" transform=\"translate({} {})\">",
+        MARGIN, MARGIN
+    )?;
+    writeln!(w, "<title>ml</title>")?;
+    for (node, rect) in model.nodes.iter().zip(rects.iter()) {
+        label(w, node, config, rect)?;
+    }
+    for (at, edge) in model.relations().enumerate() {
+        let source: Option<usize> = model.nodes.iter().position(|node| node.path == edge.source);
+        let target: Option<usize> = model.nodes.iter().position(|node| node.path == edge.target);
+
+        if let (Some(source), Some(target)) = (source, target) {
+            link(w, at, edge, &rects[source], &rects[target])?;
+        }
+    }
+    writeln!(w, "</g>")?;
+    writeln!(w, "</svg>")
+}
@@ -0,0 +1,176 @@
+//! The purpose of this module is to print a model as plain text for a terminal: each type
+//! is a box-drawing class and the relations are listed below the types of their module.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::Config;
+
+use super::{Edge, Function, Kind, Model, Node};
+
+/// The function `name` returns the path of `path` without its empty segments.
+fn name(path: &[String]) -> String {
+    path.iter()
+        .filter(|segment| !segment.is_empty())
+        .map(String::as_str)
+        .collect::<Vec<&str>>()
+        .join("::")
+}
+
+/// The function `compartments` returns the lines of the header and of the compartments of
+/// `node`, the empty compartments being left out.
+fn compartments(node: &Node, config: &Config) -> Vec<Vec<String>> {
+    let mut header: Vec<String> = Vec::new();
+    let mut compartments: Vec<Vec<String>> = Vec::new();
+
+    match node.kind {
+        Kind::Struct => {}
+        Kind::Enum => header.push("«enum»".to_string()),
+        Kind::Trait => header.push("«trait»".to_string()),
+    }
+    header.push(match node.params.is_empty() {
+        true => node.name().to_string(),
+        false => format!("{}<{}>", node.name(), node.params.join(", ")),
+    });
+    compartments.push(header);
+    if config.include_fields {
+        compartments.push(
+            node.fields
+                .iter()
+                .enumerate()
+                .map(|(at, field)| field.member(at))
+                .chain(node.variants.iter().map(|variant| match variant.fields.is_empty() {
+                    true => variant.name.clone(),
+                    false => format!("{}({})", variant.name, variant.fields.join(", ")),
+                }))
+                .collect::<Vec<String>>(),
+        );
+    }
+    if config.include_methods {
+        compartments.push(
+            node.items
+                .iter()
+                .chain(node.methods.iter())
+                .map(Function::member)
+                .collect::<Vec<String>>(),
+        );
+    }
+    if config.include_implems {
+        compartments.push(
+            node.implems
+                .iter()
+                .flat_map(|implem| {
+                    Some(format!("impl {}", implem.path.join("::"))).into_iter().chain(
+                        implem
+                            .methods
+                            .iter()
+                            .map(|method| format!("  {}", method.member())),
+                    )
+                })
+                .collect::<Vec<String>>(),
+        );
+    }
+    compartments
+        .into_iter()
+        .enumerate()
+        .filter(|&(at, ref lines)| at == 0 || !lines.is_empty())
+        .map(|(_, lines)| lines)
+        .collect::<Vec<Vec<String>>>()
+}
+
+/// The function `class` writes the box of `node`, its header centered.
+fn class<W: Write>(w: &mut W, node: &Node, config: &Config) -> io::Result<()> {
+    let compartments: Vec<Vec<String>> = compartments(node, config);
+    let width: usize = compartments
+        .iter()
+        .flat_map(|lines| lines.iter())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2;
+
+    writeln!(w, "┌{}┐", "─".repeat(width))?;
+    for (at, lines) in compartments.iter().enumerate() {
+        if at > 0 {
+            writeln!(w, "├{}┤", "─".repeat(width))?;
+        }
+        for line in lines {
+            let space: usize = width - line.chars().count();
+
+            match at {
+                0 => writeln!(
+                    w,
+                    "│{}{}{}│",
+                    " ".repeat(space / 2),
+                    line,
+                    " ".repeat(space - space / 2)
+                )?,
+                _ => writeln!(w, "│ {}{}│", line, " ".repeat(space - 1))?,
+            }
+        }
+    }
+    writeln!(w, "└{}┘", "─".repeat(width))
+}
+
+/// The function `edge2string` returns the line of `edge`, from the holder to the referenced
+/// type like *PlantUML*, with the name of the relationship.
+fn edge2string(edge: &Edge) -> String {
+    let arrow: &str = match (edge.relation, edge.reverse) {
+        (Relation::Association, Relation::Association) => "--",
+        (Relation::Composition, _) => "*--",
+        (Relation::Aggregation, _) => "o--",
+        (Relation::Realization, _) => "..|>",
+        (Relation::Dependency, _) => "..>",
+        (Relation::Association, _) => "-->",
+        (Relation::None, _) => "..",
+    };
+
+    format!(
+        "{} {} {} ({})",
+        name(&edge.target),
+        arrow,
+        name(&edge.source),
+        edge.relation.as_str()
+    )
+}
+
+/// The function `render` writes the types of `model` to `w` module by module, each type as
+/// a box followed by the relations held by the types of the module.
+pub fn render<W: Write>(model: &Model, config: &Config, w: &mut W) -> io::Result<()> {
+    let mut modules: BTreeMap<&[String], Vec<&Node>> = BTreeMap::new();
+
+    for node in model.nodes.iter() {
+        modules
+            .entry(&node.path[..node.path.len() - 1])
+            .or_insert_with(Vec::new)
+            .push(node);
+    }
+    for (at, (module, nodes)) in modules.iter().enumerate() {
+        let mut relations: Vec<String> = model
+            .relations()
+            .filter(|edge| &edge.target[..edge.target.len() - 1] == *module)
+            .map(edge2string)
+            .collect::<Vec<String>>();
+
+        relations.sort();
+        if at > 0 {
+            writeln!(w)?;
+        }
+        writeln!(
+            w,
+            "mod {}",
+            match name(module).as_str() {
+                "" => "crate".to_string(),
+                module => module.to_string(),
+            }
+        )?;
+        for node in nodes {
+            class(w, node, config)?;
+        }
+        for relation in relations {
+            writeln!(w, "{}", relation)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,309 @@
+//! The purpose of this module is to write a model as *XMI 2.5* of *UML 2.5*, to be imported
+//! by the modelling tools (Papyrus, StarUML, Enterprise Architect...).
+
+use std::ffi::OsString;
+use std::io::{self, Write};
+
+use crate::core::item::relation::Relation;
+use crate::core::path2id;
+use crate::dot::escape_html;
+use crate::module::path::ModulePath;
+
+use super::{Edge, Function, Kind, Model, Node, Visibility};
+
+/// The structure `Types` gives an identifier to the types written in the members: a node of
+/// the model when the path resolves to it in the module of the member, or else a
+/// `uml:DataType`.
+#[derive(Debug)]
+struct Types<'a> {
+    model: &'a Model,
+    datatypes: Vec<String>,
+}
+
+impl<'a> Types<'a> {
+    /// The method `id` returns the identifier of the type `ty` written in the module `scope`.
+    fn id(&mut self, scope: &ModulePath, ty: &str) -> String {
+        match scope.resolve(ty).iter().find_map(|candidate| self.model.node(candidate)) {
+            Some(node) => node.id(),
+            None => {
+                let at: usize = match self.datatypes.iter().position(|datatype| datatype == ty) {
+                    Some(at) => at,
+                    None => {
+                        self.datatypes.push(ty.to_string());
+                        self.datatypes.len() - 1
+                    }
+                };
+
+                format!("dt{}", at)
+            }
+        }
+    }
+}
+
+/// The function `scope` returns the module path which resolves the types written in the
+/// members of `node`: its package, or else its module without the `use` declarations.
+fn scope(model: &Model, node: &Node) -> ModulePath {
+    match model
+        .packages
+        .iter()
+        .find(|package| package.path.as_slice() == &node.path[..node.path.len().saturating_sub(1)])
+    {
+        Some(package) => package.scope(),
+        None => ModulePath {
+            krate: node.krate().to_string(),
+            path: node.module().iter().map(OsString::from).collect::<Vec<OsString>>(),
+            ..ModulePath::default()
+        },
+    }
+}
+
+/// The function `visibility` returns the *UML* visibility kind of `vis`.
+fn visibility(vis: &Visibility) -> &'static str {
+    match vis {
+        &Visibility::Public => "public",
+        &Visibility::Crate | &Visibility::Restricted(_) => "package",
+        &Visibility::Inherited => "private",
+    }
+}
+
+/// The function `operation` writes the `uml:Operation` `id` of the method `function` written
+/// in the module `scope`, whose inputs are types when `typed` is set (a trait's item or the
+/// method of an implementation) or else the names of the arguments.
+fn operation<W: Write>(
+    w: &mut W,
+    types: &mut Types,
+    scope: &ModulePath,
+    indent: &str,
+    id: &str,
+    function: &Function,
+    typed: bool,
+) -> io::Result<()> {
+    writeln!(
+        w,
+        "{}<ownedOperation xmi:type=\"uml:Operation\" xmi:id=\"{}\" name=\"{}\" visibility=\"{}\">",
+        indent,
+        id,
+        escape_html(&function.name),
+        visibility(&function.vis)
+    )?;
+    for (at, input) in function.inputs.iter().enumerate() {
+        let attribute: String = match typed {
+            true => format!("type=\"{}\"", types.id(scope, input)),
+            false => format!("name=\"{}\"", escape_html(input)),
+        };
+
+        writeln!(
+            w,
+            "{}  <ownedParameter xmi:type=\"uml:Parameter\" xmi:id=\"{}.p{}\" {} direction=\"in\"/>",
+            indent, id, at, attribute
+        )?;
+    }
+    if let Some(ref output) = function.output {
+        writeln!(
+            w,
+            "{}  <ownedParameter xmi:type=\"uml:Parameter\" xmi:id=\"{}.return\" direction=\"return\" type=\"{}\"/>",
+            indent,
+            id,
+            types.id(scope, output)
+        )?;
+    }
+    writeln!(w, "{}</ownedOperation>", indent)
+}
+
+/// The function `classifier` writes the `uml:Class`, `uml:Enumeration` or `uml:Interface`
+/// of `node` with its members and its interface realizations.
+fn classifier<W: Write>(w: &mut W, model: &Model, types: &mut Types, indent: &str, node: &Node) -> io::Result<()> {
+    let id: String = node.id();
+    let scope: ModulePath = scope(model, node);
+    let ty: &str = match node.kind {
+        Kind::Struct => "uml:Class",
+        Kind::Enum => "uml:Enumeration",
+        Kind::Trait => "uml:Interface",
+    };
+
+    writeln!(
+        w,
+        "{}<packagedElement xmi:type=\"{}\" xmi:id=\"{}\" name=\"{}\" visibility=\"{}\">",
+        indent,
+        ty,
+        id,
+        escape_html(node.name()),
+        visibility(&node.vis)
+    )?;
+    if !node.params.is_empty() {
+        writeln!(
+            w,
+            "{}  <ownedTemplateSignature xmi:type=\"uml:RedefinableTemplateSignature\" xmi:id=\"{}.sig\">",
+            indent, id
+        )?;
+        for (at, param) in node.params.iter().enumerate() {
+            writeln!(
+                w,
+                "{}    <ownedParameter xmi:type=\"uml:ClassifierTemplateParameter\" xmi:id=\"{id}.t{at}\" parameteredElement=\"{id}.t{at}.p\">",
+                indent,
+                id = id,
+                at = at
+            )?;
+            writeln!(
+                w,
+                "{}      <ownedParameteredElement xmi:type=\"uml:Class\" xmi:id=\"{}.t{}.p\" name=\"{}\"/>",
+                indent,
+                id,
+                at,
+                escape_html(param)
+            )?;
+            writeln!(w, "{}    </ownedParameter>", indent)?;
+        }
+        writeln!(w, "{}  </ownedTemplateSignature>", indent)?;
+    }
+    for (at, field) in node.fields.iter().enumerate() {
+        writeln!(
+            w,
+            "{}  <ownedAttribute xmi:type=\"uml:Property\" xmi:id=\"{}.f{}\" name=\"{}\" visibility=\"{}\" type=\"{}\"/>",
+            indent,
+            id,
+            at,
+            escape_html(&field.name.clone().unwrap_or_else(|| at.to_string())),
+            visibility(&field.vis),
+            types.id(&scope, &field.ty)
+        )?;
+    }
+    for (at, variant) in node.variants.iter().enumerate() {
+        writeln!(
+            w,
+            "{}  <ownedLiteral xmi:type=\"uml:EnumerationLiteral\" xmi:id=\"{}.v{}\" name=\"{}\"/>",
+            indent,
+            id,
+            at,
+            escape_html(&variant.name)
+        )?;
+    }
+    for (at, (function, typed)) in node
+        .items
+        .iter()
+        .map(|function| (function, true))
+        .chain(node.methods.iter().map(|function| (function, false)))
+        .chain(
+            node.implems
+                .iter()
+                .flat_map(|implem| implem.methods.iter().map(|function| (function, true))),
+        )
+        .enumerate()
+    {
+        operation(
+            w,
+            types,
+            &scope,
+            &format!("{}  ", indent),
+            &format!("{}.m{}", id, at),
+            function,
+            typed,
+        )?;
+    }
+    for (at, edge) in model
+        .relations()
+        .filter(|edge| edge.target == node.path && edge.relation == Relation::Realization)
+        .enumerate()
+    {
+        let contract: String = path2id(&edge.source);
+
+        writeln!(
+            w,
+            "{}  <interfaceRealization xmi:type=\"uml:InterfaceRealization\" xmi:id=\"{}.r{}\" client=\"{}\" supplier=\"{}\" contract=\"{}\" implementingClassifier=\"{}\"/>",
+            indent, id, at, id, contract, contract, id
+        )?;
+    }
+    writeln!(w, "{}</packagedElement>", indent)
+}
+
+/// The function `package` writes the classifiers of the module `path` and its submodules
+/// as nested `uml:Package`.
+fn package<W: Write>(w: &mut W, model: &Model, types: &mut Types, depth: usize, path: &[String]) -> io::Result<()> {
+    let indent: String = "  ".repeat(depth + 2);
+
+    for node in model.types_of(path) {
+        classifier(w, model, types, &indent, node)?;
+    }
+    for child in model.submodules(path) {
+        // The nodes of an unknown crate have no package of crate.
+        if child.last().map_or(true, String::is_empty) {
+            package(w, model, types, depth, child)?;
+            continue;
+        }
+        writeln!(
+            w,
+            "{}<packagedElement xmi:type=\"uml:Package\" xmi:id=\"pk{}\" name=\"{}\">",
+            indent,
+            &path2id(child)[2..],
+            escape_html(child.last().map(String::as_str).unwrap_or_default())
+        )?;
+        package(w, model, types, depth + 1, child)?;
+        writeln!(w, "{}</packagedElement>", indent)?;
+    }
+    Ok(())
+}
+
+/// The function `relation` writes the `uml:Association` or `uml:Usage` of `edge`, the
+/// interface realizations being owned by their classifier.
+fn relation<W: Write>(w: &mut W, at: usize, edge: &Edge) -> io::Result<()> {
+    let (source, target): (String, String) = (path2id(&edge.source), path2id(&edge.target));
+    let aggregation: &str = match edge.relation {
+        Relation::Composition => "composite",
+        Relation::Aggregation => "shared",
+        _ => "none",
+    };
+
+    match edge.relation {
+        Relation::Composition | Relation::Aggregation | Relation::Association => {
+            // The end typed by the referenced type is navigable from the holder,
+            // and the other one too for a both-way association.
+            let navigable: String = match edge.reverse {
+                Relation::Association if edge.relation == Relation::Association => format!("as{at}.source as{at}.target", at = at),
+                _ => format!("as{}.source", at),
+            };
+
+            writeln!(w, "    <packagedElement xmi:type=\"uml:Association\" xmi:id=\"as{at}\" memberEnd=\"as{at}.source as{at}.target\" navigableOwnedEnd=\"{navigable}\">", at = at, navigable = navigable)?;
+            writeln!(w, "      <ownedEnd xmi:type=\"uml:Property\" xmi:id=\"as{at}.source\" type=\"{source}\" association=\"as{at}\" aggregation=\"{aggregation}\"/>", at = at, source = source, aggregation = aggregation)?;
+            writeln!(w, "      <ownedEnd xmi:type=\"uml:Property\" xmi:id=\"as{at}.target\" type=\"{target}\" association=\"as{at}\"/>", at = at, target = target)?;
+            writeln!(w, "    </packagedElement>")
+        }
+        Relation::Dependency => writeln!(
+            w,
+            "    <packagedElement xmi:type=\"uml:Usage\" xmi:id=\"us{}\" client=\"{}\" supplier=\"{}\"/>",
+            at, target, source
+        ),
+        Relation::Realization | Relation::None => Ok(()),
+    }
+}
+
+/// The function `render` writes the *XMI 2.5* document of `model` to `w`.
+pub fn render<W: Write>(model: &Model, w: &mut W) -> io::Result<()> {
+    let mut types: Types = Types {
+        model: model,
+        datatypes: Vec::new(),
+    };
+    let mut classifiers: Vec<u8> = Vec::new();
+
+    // The data types are known once the classifiers are written.
+    package(&mut classifiers, model, &mut types, 0, &[])?;
+    writeln!(w, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(
+        w,
+        "<xmi:XMI xmi:version=\"20131001\" xmlns:xmi=\"http://www.omg.org/spec/XMI/20131001\" xmlns:uml=\"http://www.omg.org/spec/UML/20161101\">"
+    )?;
+    writeln!(w, "  <uml:Model xmi:type=\"uml:Model\" xmi:id=\"ml\" name=\"ml\">")?;
+    w.write_all(classifiers.as_slice())?;
+    for (at, datatype) in types.datatypes.iter().enumerate() {
+        writeln!(
+            w,
+            "    <packagedElement xmi:type=\"uml:DataType\" xmi:id=\"dt{}\" name=\"{}\"/>",
+            at,
+            escape_html(datatype)
+        )?;
+    }
+    for (at, edge) in model.relations().enumerate() {
+        relation(w, at, edge)?;
+    }
+    writeln!(w, "  </uml:Model>")?;
+    writeln!(w, "</xmi:XMI>")
+}
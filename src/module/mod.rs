@@ -4,10 +4,22 @@ use std::rc::Rc;
 use std::vec;
 
 use rustc_ast::{ast, ptr};
+use rustc_span::symbol::kw;
 
 pub mod path;
+pub mod tree;
+pub mod workspace;
 
-use self::path::ModulePath;
+use self::path::{Import, ModulePath};
+
+/// The function `is_public` returns true for the visibility `pub`, the one of the items
+/// which can be exported.
+pub fn is_public(vis: &ast::Visibility) -> bool {
+    match vis.kind {
+        ast::VisibilityKind::Public => true,
+        _ => false,
+    }
+}
 
 #[derive(Default, Debug, Clone)]
 pub struct Module {
@@ -16,12 +28,19 @@ pub struct Module {
 }
 
 impl From<(Vec<ptr::P<ast::Item>>, PathBuf)> for Module {
-    fn from((list, mut path): (Vec<ptr::P<ast::Item>>, PathBuf)) -> Module {
+    fn from((list, file): (Vec<ptr::P<ast::Item>>, PathBuf)) -> Module {
+        let mut path: PathBuf = file.clone();
+
         path.set_extension("");
         //println!("path: {:#?}", path);
         Module {
             list: list,
             path: ModulePath {
+                krate: String::new(),
+                imports: Vec::new(),
+                // the declaration of the module is unknown, so it's kept in the public API.
+                public: true,
+                file: file,
                 path: path
                     .components()
                     .skip(1)
@@ -32,16 +51,114 @@ impl From<(Vec<ptr::P<ast::Item>>, PathBuf)> for Module {
     }
 }
 
+impl Module {
+    /// The function `imports` pushes the names brought in scope by the use tree `tree`,
+    /// re-exported when `public`.
+    fn imports(tree: &ast::UseTree, prefix: &[String], public: bool, imports: &mut Vec<Import>) {
+        let mut path: Vec<String> = prefix.to_vec();
+
+        path.extend(
+            tree.prefix
+                .segments
+                .iter()
+                .filter(|segment| segment.ident.name != kw::PathRoot)
+                .map(|segment| segment.ident.name.to_string()),
+        );
+        match tree.kind {
+            ast::UseTreeKind::Simple(rename) => {
+                // `use a::{self}` imports `a`.
+                if path.last().map_or(false, |last| last == "self") {
+                    path.pop();
+                }
+                imports.push(Import {
+                    name: rename
+                        .map(|ident| ident.name.to_string())
+                        .or_else(|| path.last().cloned()),
+                    path: path,
+                    public: public,
+                });
+            }
+            ast::UseTreeKind::Nested { ref items, .. } => {
+                for &(ref tree, _) in items.iter() {
+                    Module::imports(tree, &path, public, imports);
+                }
+            }
+            ast::UseTreeKind::Glob => imports.push(Import {
+                name: None,
+                path: path,
+                public: public,
+            }),
+        }
+    }
+
+    /// The function `scope` returns the module path `path` with the imports of the `use`
+    /// declarations of `list`.
+    fn scope(list: &[ptr::P<ast::Item>], mut path: ModulePath) -> ModulePath {
+        path.imports.clear();
+        for item in list.iter() {
+            if let ast::ItemKind::Use(ref tree) = item.kind {
+                Module::imports(tree, &[], is_public(&item.vis), &mut path.imports);
+            }
+        }
+        path
+    }
+
+    /// The function `declarations` pushes the module path of `list` and the ones of its
+    /// inline `mod { ... }` blocks, with their imports.
+    fn declarations(list: &[ptr::P<ast::Item>], path: ModulePath, paths: &mut Vec<ModulePath>) {
+        let at: usize = paths.len();
+
+        paths.push(Module::scope(list, path));
+        for item in list.iter() {
+            if let ast::ItemKind::Mod(_, ast::ModKind::Loaded(ref inline, ast::Inline::Yes, ..)) = item.kind {
+                let mut subpath: ModulePath = paths[at].clone();
+
+                subpath.path.push(OsString::from(item.ident.name.as_str()));
+                subpath.public = is_public(&item.vis);
+                Module::declarations(inline, subpath, paths);
+            }
+        }
+    }
+
+    /// The method `paths` returns the path of the module followed by the ones of its inline
+    /// modules, with the imports of their `use` declarations.
+    pub fn paths(&self) -> Vec<ModulePath> {
+        let mut paths: Vec<ModulePath> = Vec::new();
+
+        Module::declarations(&self.list, self.path.clone(), &mut paths);
+        paths
+    }
+
+    /// The function `flatten` pushes the items of `list` with their module path,
+    /// descending into the inline `mod { ... }` blocks with a nested module path.
+    fn flatten(
+        list: Vec<ptr::P<ast::Item>>,
+        path: ModulePath,
+        items: &mut Vec<(ptr::P<ast::Item>, Rc<ModulePath>)>,
+    ) {
+        let path: Rc<ModulePath> = Rc::new(Module::scope(&list, path));
+
+        for item in list {
+            if let ast::ItemKind::Mod(_, ast::ModKind::Loaded(ref inline, ast::Inline::Yes, ..)) = item.kind {
+                let mut subpath: ModulePath = (*path).clone();
+
+                subpath.path.push(OsString::from(item.ident.name.as_str()));
+                subpath.public = is_public(&item.vis);
+                Module::flatten(inline.iter().cloned().collect(), subpath, items);
+            }
+            items.push((item, Rc::clone(&path)));
+        }
+    }
+}
+
 impl IntoIterator for Module {
     type Item = (ptr::P<ast::Item>, Rc<ModulePath>);
     type IntoIter = vec::IntoIter<(ptr::P<ast::Item>, Rc<ModulePath>)>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let ref rc: Rc<ModulePath> = Rc::new(self.path);
-        self.list
-            .into_iter()
-            .map(|item| (item, Rc::clone(rc)))
-            .collect::<Vec<(ptr::P<ast::Item>, Rc<ModulePath>)>>()
-            .into_iter()
+        let mut items: Vec<(ptr::P<ast::Item>, Rc<ModulePath>)> = Vec::new();
+
+        Module::flatten(self.list, self.path, &mut items);
+        items.into_iter()
     }
 }
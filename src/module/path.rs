@@ -1,6 +1,130 @@
 use std::ffi::OsString;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// The structure `Import` is a name brought in scope by a `use` declaration.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Import {
+    /// Name in scope, or `None` for a glob import.
+    pub name: Option<String>,
+    /// Imported path as written (`crate::a::B`, `super::b`, `std::rc::Rc`...).
+    pub path: Vec<String>,
+    /// Re-exported by a `pub use` declaration.
+    #[serde(default)]
+    pub public: bool,
+}
 
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct ModulePath {
+    /// Name of the crate which declares the module (empty when unknown).
+    pub krate: String,
+    /// Segments of the module from the crate root (empty for the root itself).
     pub path: Vec<OsString>,
+    /// Source file which declares the module's items.
+    pub file: PathBuf,
+    /// Imports of the module's `use` declarations.
+    pub imports: Vec<Import>,
+    /// Declared `pub` by its parent module, as a crate root is.
+    pub public: bool,
+}
+
+impl ModulePath {
+    /// The method `segments` returns the crate's tag followed by the module's segments.
+    pub fn segments(&self) -> Vec<String> {
+        let mut segments: Vec<String> = vec![self.krate.clone()];
+
+        segments.extend(self.path.iter().map(|segment| segment.to_string_lossy().into_owned()));
+        segments
+    }
+
+    /// The method `absolute` returns the path `path` from the crate root when it starts
+    /// with `crate`, `self` or `super`.
+    fn absolute(&self, path: &[String]) -> Option<Vec<String>> {
+        let mut segments: Vec<String> = self.segments();
+
+        match path.first().map(String::as_str) {
+            Some("crate") => {
+                segments.truncate(1);
+                segments.extend(path[1..].iter().cloned());
+                Some(segments)
+            }
+            Some("self") => {
+                segments.extend(path[1..].iter().cloned());
+                Some(segments)
+            }
+            Some("super") => {
+                let supers: usize = path.iter().take_while(|segment| segment.as_str() == "super").count();
+
+                // The crate's tag can't be left by `super`.
+                segments.truncate(segments.len().saturating_sub(supers).max(1));
+                segments.extend(path[supers..].iter().cloned());
+                Some(segments)
+            }
+            _ => None,
+        }
+    }
+
+    /// The method `resolve` returns the candidates paths, from the crate's tag, of the type
+    /// path `ty` written in this module, the `use` declarations taken into account.
+    pub fn resolve(&self, ty: &str) -> Vec<Vec<String>> {
+        let path: Vec<String> = ty
+            .trim()
+            .trim_start_matches("::")
+            .split("::")
+            .map(str::to_string)
+            .collect::<Vec<String>>();
+
+        if path.iter().any(String::is_empty) {
+            return Vec::new();
+        }
+        if let Some(absolute) = self.absolute(&path) {
+            return vec![absolute];
+        }
+        // The declarations relative to this module, or else the crate `path[0]`.
+        let relative = |import: &[String]| -> Vec<Vec<String>> {
+            match self.absolute(import) {
+                Some(absolute) => vec![absolute],
+                None => {
+                    let mut local: Vec<String> = self.segments();
+
+                    local.extend(import.iter().cloned());
+                    vec![local, import.to_vec()]
+                }
+            }
+        };
+
+        match self
+            .imports
+            .iter()
+            .find(|import| import.name.as_ref() == Some(&path[0]))
+        {
+            Some(import) => relative(&import.path)
+                .into_iter()
+                .map(|mut candidate: Vec<String>| {
+                    candidate.extend(path[1..].iter().cloned());
+                    candidate
+                })
+                .collect::<Vec<Vec<String>>>(),
+            None => relative(&path)
+                .into_iter()
+                .chain(
+                    self.imports
+                        .iter()
+                        .filter(|import| import.name.is_none())
+                        .flat_map(|glob: &Import| relative(&glob.path))
+                        .map(|mut candidate: Vec<String>| {
+                            candidate.extend(path.iter().cloned());
+                            candidate
+                        }),
+                )
+                .collect::<Vec<Vec<String>>>(),
+        }
+    }
+
+    /// The method `resolves_to` returns true when the type path `ty` written in this module
+    /// designates the element of path `target` (see `ItemState::as_path`).
+    pub fn resolves_to(&self, ty: &str, target: &[String]) -> bool {
+        self.resolve(ty).iter().any(|candidate| candidate.as_slice() == target)
+    }
 }
@@ -0,0 +1,128 @@
+//! The purpose of this module is to resolve the module tree of a crate from its root file
+//! (`lib.rs`/`main.rs`) by following the `mod foo;` declarations like rustc does.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use rustc_ast::{ast, attr, ptr};
+use rustc_session::parse::ParseSess;
+use rustc_span::sym;
+
+use super::path::ModulePath;
+use super::{is_public, Module};
+use crate::Error;
+
+/// The names of the files which are a crate root.
+pub const ROOT_FILES: [&'static str; 2] = ["lib.rs", "main.rs"];
+
+/// The function `root_of` returns the crate root file of a source directory, if any.
+pub fn root_of<P: AsRef<Path>>(dir: P) -> Option<PathBuf> {
+    ROOT_FILES
+        .iter()
+        .map(|name| dir.as_ref().join(name))
+        .find(|file| file.is_file())
+}
+
+/// The function `resolve` returns every module reachable from the crate root `root`,
+/// the files which fail to be parsed being skipped with their submodules and pushed
+/// to `diagnostics`.
+pub fn resolve<P: AsRef<Path>>(parse_session: &ParseSess, root: P, diagnostics: &mut Vec<Error>) -> Vec<Module> {
+    let root: &Path = root.as_ref();
+    let dir: PathBuf = root.parent().map(Path::to_path_buf).unwrap_or_default();
+    let mut modules: Vec<Module> = Vec::new();
+
+    load(parse_session, root, dir, Vec::new(), true, &mut modules, diagnostics);
+    modules
+}
+
+/// The function `load` parses the module file `file` and its out-of-line submodules,
+/// `dir` being the directory where the submodules are looked for, the module being declared
+/// `pub` when `public`.
+fn load(
+    parse_session: &ParseSess,
+    file: &Path,
+    dir: PathBuf,
+    path: Vec<OsString>,
+    public: bool,
+    modules: &mut Vec<Module>,
+    diagnostics: &mut Vec<Error>,
+) {
+    let krate: ast::Crate = match crate::file2crate(parse_session, file, diagnostics) {
+        Ok(krate) => krate,
+        Err(e) => return diagnostics.push(e),
+    };
+    let file_dir: PathBuf = file.parent().map(Path::to_path_buf).unwrap_or_default();
+    let index: usize = modules.len();
+
+    // The module comes before its submodules.
+    modules.push(Module {
+        list: Vec::new(),
+        path: ModulePath {
+            krate: String::new(),
+            path: path.clone(),
+            file: file.to_path_buf(),
+            imports: Vec::new(),
+            public: public,
+        },
+    });
+    submodules(parse_session, &krate.items, &file_dir, &dir, &path, modules, diagnostics);
+    modules[index].list = Vec::from(krate.items);
+}
+
+/// The function `submodules` loads the out-of-line modules declared among `items`,
+/// descending into the inline `mod { ... }` blocks which can declare some too.
+fn submodules(
+    parse_session: &ParseSess,
+    items: &[ptr::P<ast::Item>],
+    file_dir: &Path,
+    dir: &Path,
+    path: &Vec<OsString>,
+    modules: &mut Vec<Module>,
+    diagnostics: &mut Vec<Error>,
+) {
+    for item in items {
+        if let ast::ItemKind::Mod(_, ref kind) = item.kind {
+            let name: String = item.ident.name.to_string();
+            let mut subpath: Vec<OsString> = path.clone();
+
+            subpath.push(OsString::from(name.as_str()));
+            match kind {
+                &ast::ModKind::Loaded(ref inline, ast::Inline::Yes, ..) => {
+                    // A `#[path]` inside of an inline block is relative to the block's directory.
+                    let subdir: PathBuf = dir.join(&name);
+
+                    submodules(parse_session, inline, &subdir, &subdir, &subpath, modules, diagnostics);
+                }
+                &ast::ModKind::Loaded(_, ast::Inline::No, ..) | &ast::ModKind::Unloaded => {
+                    let found: Option<(PathBuf, PathBuf)> =
+                        match attr::first_attr_value_str_by_name(&item.attrs, sym::path) {
+                            Some(custom) => {
+                                let file: PathBuf = file_dir.join(custom.as_str());
+                                let subdir: PathBuf =
+                                    file.parent().map(Path::to_path_buf).unwrap_or_default();
+
+                                Some((file, subdir))
+                            }
+                            None => [dir.join(format!("{}.rs", name)), dir.join(&name).join("mod.rs")]
+                                .iter()
+                                .find(|file| file.is_file())
+                                .map(|file| (file.clone(), dir.join(&name))),
+                        };
+
+                    // The missing files are most often behind a `#[cfg]`, so they are skipped.
+                    if let Some((file, subdir)) = found.filter(|&(ref file, _)| file.is_file()) {
+                        load(
+                            parse_session,
+                            &file,
+                            subdir,
+                            subpath,
+                            is_public(&item.vis),
+                            modules,
+                            diagnostics,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! The purpose of this module is to read the `Cargo.toml` manifests of a package or of a
+//! workspace and to list the crates (lib, bins and examples) which it builds.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Result};
+
+/// The enumeration `TargetKind` is the kind of crate built by a Cargo target.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TargetKind {
+    Lib,
+    Bin,
+    Example,
+}
+
+/// The structure `Target` is a crate of a package with its root file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Target {
+    /// Name of the package which owns the target.
+    pub package: String,
+    /// Name of the crate.
+    pub name: String,
+    pub kind: TargetKind,
+    /// Crate root file (`lib.rs`, `main.rs`, ...).
+    pub root: PathBuf,
+}
+
+impl Target {
+    /// The method `as_krate` returns the tag given to the nodes of this crate,
+    /// the binaries and examples being prefixed so they don't collide with the library.
+    pub fn as_krate(&self) -> String {
+        match self.kind {
+            TargetKind::Lib => self.name.clone(),
+            TargetKind::Bin => format!("bin:{}", self.name),
+            TargetKind::Example => format!("example:{}", self.name),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_krate())
+    }
+}
+
+/// The function `read_manifest` returns the parsed table of a `Cargo.toml`.
+fn read_manifest(manifest: &Path) -> Result<toml::Table> {
+    fs::read_to_string(manifest)?
+        .parse::<toml::Table>()
+        .map_err(|e| Error::Config(format!("{}: {}", manifest.display(), e)))
+}
+
+/// The function `strings` returns the array of strings `key` from `table`.
+fn strings(table: Option<&toml::Table>, key: &str) -> Vec<String> {
+    table
+        .and_then(|table| table.get(key))
+        .and_then(|value| value.as_array())
+        .map(|array| {
+            array
+                .iter()
+                .filter_map(|value| value.as_str().map(str::to_string))
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default()
+}
+
+/// The function `members` expands the workspace member `pattern` with the glob syntax of
+/// Cargo (`*`, `?` and `[...]`, like `crates/*/sub`), keeping the matched packages.
+fn members(dir: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+    if !pattern.contains(['*', '?', '[']) {
+        return Ok(vec![dir.join(pattern)]);
+    }
+    // the directory of the manifest is escaped, only the member being a pattern.
+    let path: PathBuf = match dir.to_str() {
+        Some(dir) => Path::new(&glob::Pattern::escape(dir)).join(pattern),
+        None => return Err(Error::Config(format!("{}: the path isn't UTF-8", dir.display()))),
+    };
+    let mut paths: Vec<PathBuf> = glob::glob(&path.to_string_lossy())
+        .map_err(|e| Error::Config(format!("workspace member `{}`: {}", pattern, e)))?
+        .collect::<std::result::Result<Vec<PathBuf>, glob::GlobError>>()
+        .map_err(io::Error::from)?
+        .into_iter()
+        .filter(|path| path.join("Cargo.toml").is_file())
+        .collect::<Vec<PathBuf>>();
+
+    paths.sort();
+    Ok(paths)
+}
+
+/// The function `package_targets` returns the targets of the package described by `table`.
+fn package_targets(dir: &Path, table: &toml::Table) -> Vec<Target> {
+    let package: Option<&toml::Table> = table.get("package").and_then(|value| value.as_table());
+    let package: String = match package.and_then(|package| package.get("name")).and_then(|value| value.as_str()) {
+        Some(name) => name.to_string(),
+        None => return Vec::new(),
+    };
+    let auto = |key: &str| {
+        table
+            .get("package")
+            .and_then(|value| value.get(key))
+            .and_then(|value| value.as_bool())
+            .unwrap_or(true)
+    };
+    let mut targets: Vec<Target> = Vec::new();
+
+    // [lib]
+    let lib: Option<&toml::Table> = table.get("lib").and_then(|value| value.as_table());
+    let lib_root: PathBuf = match lib.and_then(|lib| lib.get("path")).and_then(|value| value.as_str()) {
+        Some(path) => dir.join(path),
+        None => dir.join("src").join("lib.rs"),
+    };
+    if lib_root.is_file() {
+        targets.push(Target {
+            name: match lib.and_then(|lib| lib.get("name")).and_then(|value| value.as_str()) {
+                Some(name) => name.to_string(),
+                None => package.replace("-", "_"),
+            },
+            package: package.clone(),
+            kind: TargetKind::Lib,
+            root: lib_root,
+        });
+    }
+
+    // [[bin]] and [[example]]
+    for (key, kind, default_dir) in [("bin", TargetKind::Bin, "src/bin"), ("example", TargetKind::Example, "examples")] {
+        let mut declared: Vec<Target> = table
+            .get(key)
+            .and_then(|value| value.as_array())
+            .map(|array| {
+                array
+                    .iter()
+                    .filter_map(|target| {
+                        let name: &str = target.get("name").and_then(|value| value.as_str())?;
+                        let root: PathBuf = match target.get("path").and_then(|value| value.as_str()) {
+                            Some(path) => dir.join(path),
+                            None if kind == TargetKind::Bin && name == package => dir.join("src").join("main.rs"),
+                            None => dir.join(default_dir).join(format!("{}.rs", name)),
+                        };
+
+                        Some(Target {
+                            package: package.clone(),
+                            name: name.to_string(),
+                            kind: kind,
+                            root: root,
+                        })
+                    })
+                    .collect::<Vec<Target>>()
+            })
+            .unwrap_or_default();
+
+        // The targets which Cargo discovers by itself.
+        if kind == TargetKind::Bin && auto("autobins") {
+            let main: PathBuf = dir.join("src").join("main.rs");
+
+            if main.is_file() && !declared.iter().any(|target| target.root == main) {
+                declared.push(Target {
+                    package: package.clone(),
+                    name: package.clone(),
+                    kind: kind,
+                    root: main,
+                });
+            }
+        }
+        if auto(if kind == TargetKind::Bin { "autobins" } else { "autoexamples" }) {
+            if let Ok(entries) = fs::read_dir(dir.join(default_dir)) {
+                let mut found: Vec<PathBuf> = entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter_map(|path| {
+                        if path.extension().map_or(false, |ext| ext == "rs") {
+                            Some(path)
+                        } else if path.join("main.rs").is_file() {
+                            Some(path.join("main.rs"))
+                        } else {
+                            None
+                        }
+                    })
+                    .filter(|root| !declared.iter().any(|target| &target.root == root))
+                    .collect::<Vec<PathBuf>>();
+
+                found.sort();
+                declared.extend(found.into_iter().filter_map(|root| {
+                    let name: &Path = if root.ends_with("main.rs") { root.parent()? } else { &root };
+
+                    Some(Target {
+                        package: package.clone(),
+                        name: name.file_stem()?.to_string_lossy().into_owned(),
+                        kind: kind,
+                        root: root.clone(),
+                    })
+                }));
+            }
+        }
+        targets.extend(declared.into_iter().filter(|target| target.root.is_file()));
+    }
+    targets
+}
+
+/// The function `targets` returns every crate of the package or workspace whose manifest
+/// is `manifest` (a `Cargo.toml` or the directory holding it).
+pub fn targets<P: AsRef<Path>>(manifest: P) -> Result<Vec<Target>> {
+    let manifest: PathBuf = if manifest.as_ref().is_dir() {
+        manifest.as_ref().join("Cargo.toml")
+    } else {
+        manifest.as_ref().to_path_buf()
+    };
+    let dir: PathBuf = manifest.parent().map(Path::to_path_buf).unwrap_or_default();
+    let table: toml::Table = read_manifest(&manifest)?;
+    let workspace: Option<&toml::Table> = table.get("workspace").and_then(|value| value.as_table());
+    let excludes: Vec<PathBuf> = strings(workspace, "exclude")
+        .iter()
+        .map(|exclude| dir.join(exclude))
+        .collect::<Vec<PathBuf>>();
+    let mut targets: Vec<Target> = package_targets(&dir, &table);
+
+    for pattern in strings(workspace, "members") {
+        for member in members(&dir, &pattern)? {
+            if member != dir && !excludes.contains(&member) {
+                targets.extend(package_targets(&member, &read_manifest(&member.join("Cargo.toml"))?));
+            }
+        }
+    }
+    Ok(targets)
+}
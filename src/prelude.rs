@@ -11,5 +11,14 @@ pub use crate::core::item::state::method::Method;
 pub use crate::core::item::state::ItemState;
 pub use crate::core::item::Item;
 pub use crate::core::segment::Segment;
+pub use crate::error::Error;
+pub use crate::graphviz::{Engine, Graphviz, Output};
+pub use crate::model::diff::{Change, Diff};
+pub use crate::model::filter::{Filter, Rule};
+pub use crate::model::focus::{Direction, Focus};
+pub use crate::model::Model;
+pub use crate::renderer::{Backend, Format, Renderer};
 pub use crate::DEFAULT_NAME_DOT;
+pub use crate::DEFAULT_NAME_JSON;
 pub use crate::DEFAULT_NAME_PNG;
+pub use crate::DEFAULT_NAME_SVG;
@@ -0,0 +1,692 @@
+//! The purpose of this module is to render the graphs with a configuration of their own,
+//! so several diagrams with different settings can be rendered by one process.
+
+use std::borrow::Cow;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use rustc_ast::{ast, ptr};
+use rustc_session::parse::ParseSess;
+use rustc_span::source_map::SourceMap;
+
+use crate::core::ListItem;
+use crate::model::diff::Diff;
+use crate::model::{self, Model};
+use crate::module::path::ModulePath;
+use crate::module::workspace::Target;
+use crate::module::{self, Module};
+use crate::graphviz::{Graphviz, Output};
+use crate::{file2crate, parse_session, src2modules, strict, workspace2modules};
+use crate::{parse_dir, parse_file, parse_revision, parse_workspace};
+use crate::{Config, Error, Result, WithConfig};
+
+/// The enumeration `Format` is a language in which the diagrams can be written.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    /// *Graphiz/Dot*.
+    Dot,
+    /// *PlantUML* class diagram.
+    PlantUml,
+    /// *Mermaid* `classDiagram`.
+    Mermaid,
+    /// Versioned JSON of the model.
+    Json,
+    /// *XMI 2.5* of *UML 2.5*.
+    Xmi,
+    /// *draw.io* (diagrams.net) mxGraph file.
+    DrawIo,
+    /// *D2* diagram language.
+    D2,
+    /// *GraphML* of the type graph.
+    GraphMl,
+    /// *Structurizr DSL* of the *C4 model* (crates and modules).
+    Structurizr,
+    /// Structured vector graphics, drawn by the renderer's backend.
+    Svg,
+    /// Self-contained HTML page of the structured vector graphics, to browse the types.
+    Html,
+    /// Box-drawing classes and relations as plain text, for a terminal.
+    Text,
+    /// Package diagram of the modules for *Graphviz/Dot*.
+    Package,
+}
+
+impl Format {
+    /// The method `extension` returns the extension of the files of this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            &Format::Dot => "dot",
+            &Format::PlantUml => "puml",
+            &Format::Mermaid => "mmd",
+            &Format::Json => "json",
+            &Format::Xmi => "xmi",
+            &Format::DrawIo => "drawio",
+            &Format::D2 => "d2",
+            &Format::GraphMl => "graphml",
+            &Format::Structurizr => "dsl",
+            &Format::Svg => "svg",
+            &Format::Html => "html",
+            &Format::Text => "txt",
+            &Format::Package => "dot",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(format: &str) -> Result<Format> {
+        match format {
+            "dot" => Ok(Format::Dot),
+            "plantuml" | "puml" => Ok(Format::PlantUml),
+            "mermaid" | "mmd" => Ok(Format::Mermaid),
+            "json" => Ok(Format::Json),
+            "xmi" => Ok(Format::Xmi),
+            "drawio" | "diagrams.net" => Ok(Format::DrawIo),
+            "d2" => Ok(Format::D2),
+            "graphml" => Ok(Format::GraphMl),
+            "structurizr" | "c4" => Ok(Format::Structurizr),
+            "svg" => Ok(Format::Svg),
+            "html" => Ok(Format::Html),
+            "text" | "txt" => Ok(Format::Text),
+            "package" | "modules" => Ok(Format::Package),
+            format => Err(Error::Config(format!("unknown format {}", format))),
+        }
+    }
+}
+
+/// The enumeration `Backend` is the way the structured vector graphics are drawn.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Backend {
+    /// The `dot` binary of *Graphviz*.
+    Graphviz,
+    /// The built-in layered layout, without external binary.
+    Builtin,
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::Graphviz
+    }
+}
+
+impl FromStr for Backend {
+    type Err = Error;
+
+    fn from_str(backend: &str) -> Result<Backend> {
+        match backend {
+            "graphviz" | "dot" => Ok(Backend::Graphviz),
+            "builtin" => Ok(Backend::Builtin),
+            backend => Err(Error::Config(format!("unknown backend {}", backend))),
+        }
+    }
+}
+
+/// The structure `Renderer` generates the graphs with its own configuration.
+///
+/// # Examples
+/// ```
+/// extern crate rust2uml;
+///
+/// fn main() {
+///     let mut config = rust2uml::Config::default();
+///
+///     config.include_methods = false;
+///     let bare = rust2uml::Renderer::from(config);
+///     let full = rust2uml::Renderer::default();
+///
+///     let _ = bare.src2dot("src");
+///     let _ = full.src2dot("src");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Renderer {
+    config: Config,
+    backend: Backend,
+    graphviz: Graphviz,
+}
+
+impl Renderer {
+    /// The constructor `new` returns a renderer with the configuration `config`, drawing
+    /// with *Graphviz*.
+    pub fn new(config: Config) -> Self {
+        Renderer {
+            config: config,
+            backend: Backend::default(),
+            graphviz: Graphviz::default(),
+        }
+    }
+
+    /// The method `config` returns the configuration of the renderer.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The method `config_mut` returns the configuration of the renderer to be adjusted.
+    pub fn config_mut(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    /// The method `backend` returns the way the renderer draws the structured vector graphics.
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /// The method `set_backend` replaces the way the renderer draws the structured vector
+    /// graphics.
+    pub fn set_backend(&mut self, backend: Backend) {
+        self.backend = backend;
+    }
+
+    /// The method `graphviz` returns the way the renderer runs *Graphviz*.
+    pub fn graphviz(&self) -> &Graphviz {
+        &self.graphviz
+    }
+
+    /// The method `graphviz_mut` returns the way the renderer runs *Graphviz* to be adjusted
+    /// (binary, engine, output format and timeout).
+    pub fn graphviz_mut(&mut self) -> &mut Graphviz {
+        &mut self.graphviz
+    }
+
+    /// The method `selected` returns the model `model` without the types left out by the
+    /// configuration's filter, nor their relations, only its public API with `public_api`.
+    fn selected<'a>(&self, model: &'a Model) -> Cow<'a, Model> {
+        match (self.config.public_api, self.config.filter.is_empty()) {
+            (false, true) => Cow::Borrowed(model),
+            (false, false) => Cow::Owned(model.filter(&self.config.filter)),
+            (true, true) => Cow::Owned(model.public_api()),
+            (true, false) => Cow::Owned(model.public_api().filter(&self.config.filter)),
+        }
+    }
+
+    /// The method `filtered` returns the model `model` as drawn by every format: its selected
+    /// types (see `selected`), only the configuration's `focus` when set.
+    fn filtered<'a>(&self, model: &'a Model) -> Result<Cow<'a, Model>> {
+        match self.config.focus {
+            Some(ref focus) => self.selected(model).focus(focus).map(Cow::Owned),
+            None => Ok(self.selected(model)),
+        }
+    }
+
+    /// The method `modules2dot` returns a graph formated for *Graphiz/Dot*, whose spans are
+    /// looked up in `source_map` for the clusters of modules (see `Config::cluster_depth`).
+    fn modules2dot(&self, modules: Vec<Module>, source_map: &SourceMap) -> Result<Vec<u8>> {
+        if self.config.cluster_depth.is_some() || self.config.focus.is_some() || self.config.public_api {
+            return self.model2dot(&Model::from((modules, source_map)));
+        }
+        let mut f: Vec<u8> = Vec::new();
+        let itt: Vec<(ptr::P<ast::Item>, Rc<ModulePath>)> = modules
+            .into_iter()
+            .flat_map(|s: Module| s.into_iter())
+            .collect::<Vec<(ptr::P<ast::Item>, Rc<ModulePath>)>>();
+        let it: ListItem = ListItem::from((itt.as_slice().into_iter().peekable(), &self.config));
+
+        dot::render(&it, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `model2dot` returns the model `model` formated for *Graphiz/Dot*, its types
+    /// in the clusters of their modules with a `cluster_depth`, only the `focus` when set.
+    pub fn model2dot(&self, model: &Model) -> Result<Vec<u8>> {
+        self.filtered2dot(&self.filtered(model)?)
+    }
+
+    /// The method `filtered2dot` returns the model `model`, already filtered (see `filtered`),
+    /// formated for *Graphiz/Dot*.
+    fn filtered2dot(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        match self.config.cluster_depth {
+            Some(depth) => model::dot::render_clusters(model, &self.config, depth, &mut f)?,
+            None => dot::render(&WithConfig(model, &self.config), &mut f)?,
+        }
+        Ok(f)
+    }
+
+    /// The method `json2dot` returns graphed model from its JSON file (see `Model::to_json`).
+    pub fn json2dot<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        self.model2dot(&Model::read_json(File::open(path)?)?)
+    }
+
+    /// The method `rs2dot` returns graphed file module.
+    pub fn rs2dot<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
+            let parse_session: ParseSess = parse_session();
+            let mut diagnostics: Vec<Error> = Vec::new();
+            let parse: Result<ast::Crate> = file2crate(&parse_session, path.as_ref(), &mut diagnostics);
+
+            parse.and_then(|parse: ast::Crate| strict((parse, diagnostics))).and_then(|parse: ast::Crate| {
+                self.modules2dot(
+                    vec![Module::from((
+                        Vec::from(parse.items.clone()),
+                        path.as_ref().to_path_buf(),
+                    ))],
+                    parse_session.source_map(),
+                )
+            })
+        })
+    }
+
+    /// The method `crate2dot_partial` returns graphed crate from its root file, skipping
+    /// the files which fail to be parsed, with their diagnostics.
+    pub fn crate2dot_partial<P: AsRef<Path>>(&self, path: P) -> Result<(Vec<u8>, Vec<Error>)> {
+        rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
+            let parse_session: ParseSess = parse_session();
+            let mut diagnostics: Vec<Error> = Vec::new();
+            let modules: Vec<Module> = module::tree::resolve(&parse_session, path, &mut diagnostics);
+
+            self.modules2dot(modules, parse_session.source_map()).map(|content: Vec<u8>| (content, diagnostics))
+        })
+    }
+
+    /// The method `crate2dot` returns graphed crate from its root file (`lib.rs`/`main.rs`),
+    /// following the `mod` declarations like rustc does.
+    pub fn crate2dot<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        self.crate2dot_partial(path).and_then(strict)
+    }
+
+    /// The method `src2dot_partial` returns graphed repository of modules, skipping
+    /// the files which fail to be parsed, with their diagnostics.
+    pub fn src2dot_partial<P: AsRef<Path>>(&self, path: P) -> Result<(Vec<u8>, Vec<Error>)> {
+        rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
+            let parse_session: ParseSess = parse_session();
+            let mut diagnostics: Vec<Error> = Vec::new();
+            let modules: Vec<Module> = src2modules(&parse_session, path.as_ref(), &mut diagnostics);
+
+            self.modules2dot(modules, parse_session.source_map()).map(|content: Vec<u8>| (content, diagnostics))
+        })
+    }
+
+    /// The method `src2dot` returns graphed repository of modules.
+    pub fn src2dot<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        self.src2dot_partial(path).and_then(strict)
+    }
+
+    /// The method `workspace2dot_partial` returns one graph of every crate of a package or
+    /// workspace, skipping the files which fail to be parsed, with their diagnostics.
+    pub fn workspace2dot_partial<P: AsRef<Path>>(&self, manifest: P) -> Result<(Vec<u8>, Vec<Error>)> {
+        rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
+            let parse_session: ParseSess = parse_session();
+            let mut diagnostics: Vec<Error> = Vec::new();
+            let crates: Vec<(Target, Vec<Module>)> = workspace2modules(&parse_session, manifest.as_ref(), &mut diagnostics)?;
+
+            self.modules2dot(
+                crates.into_iter().flat_map(|(_, modules)| modules).collect::<Vec<Module>>(),
+                parse_session.source_map(),
+            )
+            .map(|content: Vec<u8>| (content, diagnostics))
+        })
+    }
+
+    /// The method `workspace2dot` returns one graph of every crate (lib, bins and examples)
+    /// of a package or workspace from its `Cargo.toml`.
+    pub fn workspace2dot<P: AsRef<Path>>(&self, manifest: P) -> Result<Vec<u8>> {
+        self.workspace2dot_partial(manifest).and_then(strict)
+    }
+
+    /// The method `workspace2dots` returns a graph by crate of a package or workspace
+    /// from its `Cargo.toml`, with the crate's tag.
+    pub fn workspace2dots<P: AsRef<Path>>(&self, manifest: P) -> Result<Vec<(String, Vec<u8>)>> {
+        rustc_span::create_session_if_not_set_then(rustc_span::edition::LATEST_STABLE_EDITION, |_sg| {
+            let parse_session: ParseSess = parse_session();
+            let mut diagnostics: Vec<Error> = Vec::new();
+            let crates: Vec<(Target, Vec<Module>)> = workspace2modules(&parse_session, manifest.as_ref(), &mut diagnostics)?;
+
+            strict((crates, diagnostics))?
+                .into_iter()
+                .map(|(target, modules): (Target, Vec<Module>)| {
+                    self.modules2dot(modules, parse_session.source_map()).map(|content: Vec<u8>| (target.as_krate(), content))
+                })
+                .collect::<Result<Vec<(String, Vec<u8>)>>>()
+        })
+    }
+
+    /// The method `model2plantuml` returns the *PlantUML* class diagram of the model `model`.
+    pub fn model2plantuml(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::plantuml::render(&self.filtered(model)?, &self.config, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `rs2plantuml` returns the *PlantUML* class diagram of a file module.
+    pub fn rs2plantuml<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_file(path).and_then(|model: Model| self.model2plantuml(&model))
+    }
+
+    /// The method `src2plantuml` returns the *PlantUML* class diagram of a repository of modules.
+    pub fn src2plantuml<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2plantuml(&model))
+    }
+
+    /// The method `model2mermaid` returns the *Mermaid* `classDiagram` of the model `model`.
+    pub fn model2mermaid(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::mermaid::render(&self.filtered(model)?, &self.config, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `rs2mermaid` returns the *Mermaid* `classDiagram` of a file module.
+    pub fn rs2mermaid<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_file(path).and_then(|model: Model| self.model2mermaid(&model))
+    }
+
+    /// The method `src2mermaid` returns the *Mermaid* `classDiagram` of a repository of modules.
+    pub fn src2mermaid<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2mermaid(&model))
+    }
+
+    /// The method `model2xmi` returns the *XMI 2.5* document of the model `model`.
+    pub fn model2xmi(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::xmi::render(&self.filtered(model)?, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2xmi` returns the *XMI 2.5* document of a repository of modules.
+    pub fn src2xmi<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2xmi(&model))
+    }
+
+    /// The method `model2drawio` returns the *draw.io* file of the model `model`.
+    pub fn model2drawio(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::drawio::render(&self.filtered(model)?, &self.config, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2drawio` returns the *draw.io* file of a repository of modules.
+    pub fn src2drawio<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2drawio(&model))
+    }
+
+    /// The method `model2d2` returns the *D2* diagram of the model `model`.
+    pub fn model2d2(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::d2::render(&self.filtered(model)?, &self.config, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2d2` returns the *D2* diagram of a repository of modules.
+    pub fn src2d2<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2d2(&model))
+    }
+
+    /// The method `model2graphml` returns the *GraphML* document of the model `model`.
+    pub fn model2graphml(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::graphml::render(&self.filtered(model)?, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2graphml` returns the *GraphML* document of a repository of modules.
+    pub fn src2graphml<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2graphml(&model))
+    }
+
+    /// The method `model2structurizr` returns the *Structurizr DSL* workspace of the model
+    /// `model`, with the types and their relations as properties when `code` is set.
+    pub fn model2structurizr(&self, model: &Model, code: bool) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::structurizr::render(&self.filtered(model)?, code, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2structurizr` returns the *Structurizr DSL* workspace of a repository
+    /// of modules.
+    pub fn src2structurizr<P: AsRef<Path>>(&self, path: P, code: bool) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2structurizr(&model, code))
+    }
+
+    /// The method `model2text` returns the types of the model `model` as plain text.
+    pub fn model2text(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::text::render(&self.filtered(model)?, &self.config, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2text` returns the types of a repository of modules as plain text.
+    pub fn src2text<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2text(&model))
+    }
+
+    /// The method `model2package` returns the package diagram of the model `model` for
+    /// *Graphviz/Dot*: the modules nested in clusters and their weighted dependencies.
+    pub fn model2package(&self, model: &Model) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::package::render(&self.filtered(model)?, &self.config, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2package` returns the package diagram of a repository of modules.
+    pub fn src2package<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2package(&model))
+    }
+
+    /// The method `diff` returns the comparison of the model `old` with the model `new`, both
+    /// cut like the diagrams (see `Config::filter` and `Config::public_api`).
+    pub fn diff(&self, old: &Model, new: &Model) -> Diff {
+        self.selected(old).diff(&self.selected(new))
+    }
+
+    /// The method `diff2dot` returns the graph of both models of `diff` for *Graphiz/Dot*: the
+    /// added types, members and relations in green, the removed ones in red and the changed
+    /// ones in yellow.
+    pub fn diff2dot(&self, diff: &Diff) -> Result<Vec<u8>> {
+        let mut f: Vec<u8> = Vec::new();
+
+        model::diff::render(diff, &self.config, &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2diff` returns the comparison of the repositories of modules `old` and
+    /// `new`.
+    pub fn src2diff<P: AsRef<Path>>(&self, old: P, new: P) -> Result<Diff> {
+        let old: Model = parse_dir(old)?;
+
+        parse_dir(new).map(|new: Model| self.diff(&old, &new))
+    }
+
+    /// The method `git2diff` returns the comparison of the repository of modules `path` (from
+    /// the root of the *git* repository `repo`) at the revisions `old` and `new`.
+    pub fn git2diff<P: AsRef<Path>>(&self, repo: P, path: P, old: &str, new: &str) -> Result<Diff> {
+        let old: Model = parse_revision(repo.as_ref(), old, path.as_ref())?;
+
+        parse_revision(repo.as_ref(), new, path.as_ref()).map(|new: Model| self.diff(&old, &new))
+    }
+
+    /// The method `model2format` returns the diagram of the model `model` written in `format`.
+    pub fn model2format(&self, model: &Model, format: Format) -> Result<Vec<u8>> {
+        match format {
+            Format::Dot => self.model2dot(model),
+            Format::PlantUml => self.model2plantuml(model),
+            Format::Mermaid => self.model2mermaid(model),
+            Format::Json => self.filtered(model)?.to_json().map(String::into_bytes),
+            Format::Xmi => self.model2xmi(model),
+            Format::DrawIo => self.model2drawio(model),
+            Format::D2 => self.model2d2(model),
+            Format::GraphMl => self.model2graphml(model),
+            Format::Structurizr => self.model2structurizr(model, false),
+            Format::Svg => self.model2svg(model),
+            Format::Html => self.model2html(model),
+            Format::Text => self.model2text(model),
+            Format::Package => self.model2package(model),
+        }
+    }
+
+    /// The method `model2graphviz` returns the model `model` laid out and written in `output`
+    /// by *Graphviz*.
+    pub fn model2graphviz(&self, model: &Model, output: Output) -> Result<Vec<u8>> {
+        self.model2dot(model).and_then(|buf| self.graphviz.render(buf.as_slice(), output))
+    }
+
+    /// The method `src2graphviz` returns a repository of modules laid out and written in
+    /// `output` by *Graphviz*.
+    pub fn src2graphviz<P: AsRef<Path>>(&self, path: P, output: Output) -> Result<Vec<u8>> {
+        self.src2dot(path).and_then(|buf| self.graphviz.render(buf.as_slice(), output))
+    }
+
+    /// The method `model2svg` returns the structured vector graphics of the model `model`.
+    pub fn model2svg(&self, model: &Model) -> Result<Vec<u8>> {
+        self.filtered2svg(&self.filtered(model)?)
+    }
+
+    /// The method `filtered2svg` returns the structured vector graphics of the model `model`,
+    /// already filtered (see `filtered`), drawn by the renderer's backend.
+    fn filtered2svg(&self, model: &Model) -> Result<Vec<u8>> {
+        match self.backend {
+            Backend::Graphviz => self
+                .filtered2dot(model)
+                .and_then(|buf| self.graphviz.render(buf.as_slice(), Output::Svg)),
+            Backend::Builtin => {
+                let mut f: Vec<u8> = Vec::new();
+
+                model::svg::render(model, &self.config, &mut f)?;
+                Ok(f)
+            }
+        }
+    }
+
+    /// The method `rs2svg` returns structured vector graphics file modules.
+    pub fn rs2svg<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        match self.backend {
+            Backend::Graphviz => self.rs2dot(path).and_then(|buf| self.graphviz.render(buf.as_slice(), Output::Svg)),
+            Backend::Builtin => parse_file(path).and_then(|model: Model| self.model2svg(&model)),
+        }
+    }
+
+    /// The method `src2svg` returns structured vector graphics repository of modules.
+    pub fn src2svg<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        match self.backend {
+            Backend::Graphviz => self.src2graphviz(path, Output::Svg),
+            Backend::Builtin => parse_dir(path).and_then(|model: Model| self.model2svg(&model)),
+        }
+    }
+
+    /// The method `model2html` returns the self-contained HTML page of the model `model`,
+    /// around its structured vector graphics drawn by the renderer's backend.
+    pub fn model2html(&self, model: &Model) -> Result<Vec<u8>> {
+        let model: &Model = &self.filtered(model)?;
+        let svg: Vec<u8> = self.filtered2svg(model)?;
+        let mut f: Vec<u8> = Vec::new();
+
+        model::html::render(model, &self.config, svg.as_slice(), &mut f)?;
+        Ok(f)
+    }
+
+    /// The method `src2html` returns the self-contained HTML page of a repository of modules.
+    pub fn src2html<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        parse_dir(path).and_then(|model: Model| self.model2html(&model))
+    }
+
+    /// The method `write_both` creates the graph/dot file `name` and its picture, written by
+    /// *Graphviz* in its output format.
+    fn write_both(&self, dest: &Path, name: &str, content_dot: Vec<u8>) -> Result<()> {
+        let mut file_dot = File::create(dest.join(name).with_extension("dot"))?;
+        let mut file_image = File::create(dest.join(name).with_extension(self.graphviz.output.as_str()))?;
+        let _ = file_dot.write_all(content_dot.as_slice())?;
+        let content_image: Vec<u8> = self.graphviz.render(content_dot.as_slice(), self.graphviz.output)?;
+        let _ = file_image.write_all(content_image.as_slice())?;
+        Ok(())
+    }
+
+    /// The method `model2both` creates the graph/dot file `name` of the model `model` and
+    /// its picture, drawn by the renderer's backend.
+    fn model2both(&self, model: &Model, dest: &Path, name: &str) -> Result<()> {
+        let model: &Model = &self.filtered(model)?;
+
+        match self.backend {
+            Backend::Graphviz => self.write_both(dest, name, self.filtered2dot(model)?),
+            Backend::Builtin => {
+                let mut file_dot = File::create(dest.join(name).with_extension("dot"))?;
+                let mut file_svg = File::create(dest.join(name).with_extension("svg"))?;
+                let _ = file_dot.write_all(self.filtered2dot(model)?.as_slice())?;
+                let _ = file_svg.write_all(self.filtered2svg(model)?.as_slice())?;
+                Ok(())
+            }
+        }
+    }
+
+    /// The method `src2both` creates two files formated like a graph/dot and a structured vector graphics
+    /// (or the output format of *Graphviz*).
+    pub fn src2both<P: AsRef<Path>>(&self, src: P, dest: P) -> Result<()> {
+        let _ = fs::create_dir_all(dest.as_ref())?;
+        match self.backend {
+            Backend::Graphviz => self.write_both(dest.as_ref(), "ml", self.src2dot(src)?),
+            Backend::Builtin => parse_dir(src).and_then(|model: Model| self.model2both(&model, dest.as_ref(), "ml")),
+        }
+    }
+
+    /// The method `diff2both` creates the graph/dot file `ml_diff` of `diff` and its picture,
+    /// written by *Graphviz*, with the summary of the changes `ml_diff.json`; the built-in
+    /// backend, which doesn't draw the changes, is refused.
+    pub fn diff2both<P: AsRef<Path>>(&self, diff: &Diff, dest: P) -> Result<()> {
+        if self.backend == Backend::Builtin {
+            return Err(Error::Config("the builtin backend doesn't draw the diff, use graphviz".to_string()));
+        }
+        let _ = fs::create_dir_all(dest.as_ref())?;
+        self.write_both(dest.as_ref(), "ml_diff", self.diff2dot(diff)?)?;
+        fs::write(dest.as_ref().join("ml_diff.json"), diff.to_json()?)?;
+        Ok(())
+    }
+
+    /// The method `json2both` creates a graph/dot and a structured vector graphics file
+    /// from the JSON file of a model.
+    pub fn json2both<P: AsRef<Path>>(&self, json: P, dest: P) -> Result<()> {
+        let _ = fs::create_dir_all(dest.as_ref())?;
+        let model: Model = Model::read_json(File::open(json)?)?;
+
+        self.model2both(&model, dest.as_ref(), "ml")
+    }
+
+    /// The method `workspace2both` creates a graph/dot and a structured vector graphics file
+    /// of a package or workspace, combined or else one pair by crate named after the crate.
+    pub fn workspace2both<P: AsRef<Path>>(&self, manifest: P, dest: P, per_crate: bool) -> Result<()> {
+        let _ = fs::create_dir_all(dest.as_ref())?;
+        match (self.backend, per_crate) {
+            (Backend::Graphviz, true) => {
+                for (krate, content_dot) in self.workspace2dots(manifest)? {
+                    self.write_both(dest.as_ref(), &krate.replace(":", "-"), content_dot)?;
+                }
+                Ok(())
+            }
+            (Backend::Graphviz, false) => self.write_both(dest.as_ref(), "ml", self.workspace2dot(manifest)?),
+            (Backend::Builtin, true) => {
+                let model: Model = parse_workspace(manifest)?;
+
+                for krate in model.krates() {
+                    self.model2both(&model.of_krate(krate), dest.as_ref(), &krate.replace(":", "-"))?;
+                }
+                Ok(())
+            }
+            (Backend::Builtin, false) => {
+                parse_workspace(manifest).and_then(|model: Model| self.model2both(&model, dest.as_ref(), "ml"))
+            }
+        }
+    }
+}
+
+impl Default for Renderer {
+    /// The constructor `default` returns a renderer with the global configuration.
+    fn default() -> Self {
+        Renderer::new(Config::global())
+    }
+}
+
+impl From<Config> for Renderer {
+    fn from(config: Config) -> Self {
+        Renderer::new(config)
+    }
+}
@@ -0,0 +1,38 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Config, Renderer};
+
+#[test]
+fn test_cluster() {
+    let mut config: Config = Config::default();
+
+    config.cluster_depth = Some(1);
+    let dot: String = String::from_utf8(Renderer::from(config).src2dot("tests/fixtures/package").unwrap()).unwrap();
+
+    assert!(dot.starts_with("digraph ml {\n    ndApp[label=<"));
+    assert!(dot.contains("    subgraph cluster_view {\n        label=\"view\";\n        ndview__List[label=<"));
+    // `view::row` is merged into `view` below the depth.
+    assert!(dot.contains("        ndview__row__Row[label=<"));
+    assert!(!dot.contains("cluster_view__row"));
+    assert!(dot.contains("    subgraph cluster_model {\n        label=\"model\";\n        ndmodel__Item[label=<"));
+    assert!(dot.contains("    ndview__List -> ndApp[label=\"\"][color=\"black\"]"));
+}
+
+#[test]
+fn test_cluster_nested() {
+    let mut config: Config = Config::default();
+
+    config.cluster_depth = Some(2);
+    let dot: String = String::from_utf8(
+        Renderer::from(config)
+            .model2dot(&rust2uml::parse_dir("tests/fixtures/package").unwrap())
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert!(dot.contains(
+        "        subgraph cluster_view__row {\n            label=\"view::row\";\n            ndview__row__Row[label=<"
+    ));
+}
@@ -0,0 +1,15 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_d2() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let d2: String = String::from_utf8(rust2uml::src2d2("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(d2.starts_with("direction: down\n\"Canvas\": \"Canvas\" {\n  shape: class\n  \"-shapes\": \"Vec<shape::Circle>\"\n"));
+    assert!(d2.contains("\"shape\": {\n  \"Area\": \"Area\" {\n    shape: class\n    \"+area(&Self)\": \"f64\"\n  }\n"));
+    assert!(d2.contains("\"shape\".\"Circle\" -> \"Canvas\": {\n  target-arrowhead.shape: diamond\n  target-arrowhead.style.filled: true\n}\n"));
+    assert!(d2.contains("\"shape\".\"Area\" -> \"shape\".\"Circle\": {\n  target-arrowhead.shape: triangle\n  target-arrowhead.style.filled: false\n  style.stroke-dash: 3\n}\n"));
+}
@@ -0,0 +1,47 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Backend, Change, Config, Diff, Error, Renderer};
+
+#[test]
+fn test_diff() {
+    let renderer: Renderer = Renderer::from(Config::default());
+    let diff: Diff = renderer.src2diff("tests/fixtures/diff/old", "tests/fixtures/diff/new").unwrap();
+    let dot: String = String::from_utf8(renderer.diff2dot(&diff).unwrap()).unwrap();
+
+    assert!(dot.contains("    ndPen[label=<"));
+    assert!(dot.contains("<td bgcolor=\"red\">"));
+    assert!(dot.contains("<td bgcolor=\"green\">"));
+    assert!(dot.contains("<td bgcolor=\"gold\">"));
+    assert!(dot.contains("<font color=\"green\">"));
+    assert!(dot.contains("ndArea -> ndCircle"));
+}
+
+#[test]
+fn test_diff_model() {
+    let diff: Diff = rust2uml::src2diff("tests/fixtures/diff/old", "tests/fixtures/diff/new").unwrap();
+    let path = |segments: &[&str]| segments.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+    let json: String = diff.to_json().unwrap();
+
+    assert_eq!(diff.change(&path(&["", "Pen"])), Some(Change::Removed));
+    assert_eq!(diff.change(&path(&["", "Area"])), Some(Change::Added));
+    assert_eq!(diff.change(&path(&["", "Canvas"])), Some(Change::Changed));
+    assert_eq!(diff.change(&path(&["", "Circle"])), None);
+    assert!(json.contains("\"removed\""));
+    assert!(json.contains("name: String"));
+}
+
+#[test]
+fn test_diff_builtin() {
+    let mut renderer: Renderer = Renderer::from(Config::default());
+    let diff: Diff = renderer.src2diff("tests/fixtures/diff/old", "tests/fixtures/diff/new").unwrap();
+    let dest = std::env::temp_dir().join("rust2uml_diff_builtin");
+
+    renderer.set_backend(Backend::Builtin);
+    match renderer.diff2both(&diff, &dest) {
+        Err(Error::Config(message)) => assert!(message.contains("graphviz")),
+        _ => panic!("a configuration error was expected"),
+    }
+    assert!(!dest.join("ml_diff.dot").exists());
+}
@@ -0,0 +1,29 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_drawio() {
+    let mut config = rust2uml::Config::default();
+
+    config.struct_header_bgcolor = "#dae8fc".to_string();
+
+    let drawio: String =
+        String::from_utf8(rust2uml::Renderer::from(config).src2drawio("tests/fixtures/xmi").unwrap()).unwrap();
+    let geometry = |id: &str| -> f64 {
+        let cell: &str = drawio.split(&format!("<mxCell id=\"{}\" ", id)).nth(1).unwrap();
+        let y: &str = cell.split(" y=\"").nth(1).unwrap();
+
+        y[..y.find('"').unwrap()].parse::<f64>().unwrap()
+    };
+
+    assert!(drawio.starts_with("<mxfile host=\"rust2uml\">"));
+    assert!(drawio.contains("<mxCell id=\"ndCanvas\" value=\"Canvas\" style=\"swimlane;"));
+    assert!(drawio.contains("fillColor=#dae8fc;"));
+    assert!(drawio.contains("value=\"&amp;lt;&amp;lt;interface&amp;gt;&amp;gt; Area\""));
+    assert!(drawio.contains("style=\"endArrow=diamondThin;endFill=1;endSize=14;html=1;\" edge=\"1\" parent=\"1\" source=\"ndshape__Circle\" target=\"ndCanvas\""));
+    assert!(drawio.contains("style=\"endArrow=block;endFill=0;dashed=1;html=1;\" edge=\"1\" parent=\"1\" source=\"ndshape__Area\" target=\"ndshape__Circle\""));
+    // The referenced types are laid out above their holders.
+    assert!(geometry("ndshape__Area") < geometry("ndshape__Circle"));
+    assert!(geometry("ndshape__Circle") < geometry("ndCanvas"));
+}
@@ -0,0 +1,73 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Config, Filter, Model, Renderer, Rule};
+
+#[test]
+fn test_filter() {
+    let mut config: Config = Config::default();
+
+    config.filter.exclude.push("kind:trait".parse::<Rule>().unwrap());
+    let dot: String = String::from_utf8(Renderer::from(config).src2dot("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(dot.contains("    ndshape__Circle[label=<"));
+    assert!(dot.contains("ndshape__Circle -> ndCanvas"));
+    // the trait `Area` is left out with its realization.
+    assert!(!dot.contains("ndshape__Area"));
+}
+
+#[test]
+fn test_filter_model() {
+    let model: Model = rust2uml::parse_dir("tests/fixtures/xmi").unwrap();
+    let path = |segments: &[&str]| segments.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+    let filter: Filter = Filter {
+        include: vec!["module:shape::*".parse::<Rule>().unwrap()],
+        exclude: vec!["name:/^P/".parse::<Rule>().unwrap()],
+    };
+    let filtered: Model = model.filter(&filter);
+
+    assert!(filtered.node(&path(&["", "shape", "Circle"])).is_some());
+    assert!(filtered.node(&path(&["", "shape", "Pen"])).is_none());
+    assert!(filtered.node(&path(&["", "Canvas"])).is_none());
+    assert!(filtered.edges.iter().all(|edge| edge.target != path(&["", "Canvas"])));
+
+    assert!("size:Circle".parse::<Rule>().is_err());
+    assert!("name:/(/".parse::<Rule>().is_err());
+}
+
+#[test]
+fn test_filter_list() {
+    let rules: Vec<Rule> = Rule::list("name:/^E{1,2}rror$/, kind:enum,module:proto::*").unwrap();
+
+    assert_eq!(rules.len(), 3);
+    assert!(rules[0].is_match("Error"));
+    assert!(rules[0].is_match("EError"));
+    assert!(!rules[0].is_match("EEError"));
+    assert!(rules[1].is_match("enum"));
+    assert!(rules[2].is_match("proto::gen"));
+    assert!(Rule::list("name:/^E{1,2/,kind:enum").is_err());
+}
+
+#[test]
+fn test_filter_workspace() {
+    let model: Model = rust2uml::parse_workspace("tests/fixtures/workspace").unwrap();
+    let path = |segments: &[&str]| segments.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+    // the root module of the binary `two` only, the library `one` keeping its own.
+    let filter: Filter = Filter {
+        include: Vec::new(),
+        exclude: vec!["module:bin:two".parse::<Rule>().unwrap()],
+    };
+    let filtered: Model = model.filter(&filter);
+
+    assert!(filtered.node(&path(&["one", "One"])).is_some());
+    assert!(filtered.node(&path(&["bin:two", "Two"])).is_none());
+
+    // a rule without tag still matches the module of every crate.
+    let filter: Filter = Filter {
+        include: Vec::new(),
+        exclude: vec!["module:".parse::<Rule>().unwrap()],
+    };
+
+    assert!(model.filter(&filter).nodes.is_empty());
+}
@@ -0,0 +1,2 @@
+pub struct Bad {
+    field: u8,
@@ -0,0 +1,18 @@
+pub struct Canvas {
+    shapes: Vec<Circle>,
+    pub name: String,
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub trait Area {
+    fn area(&self) -> f64;
+}
+
+impl Area for Circle {
+    fn area(&self) -> f64 {
+        self.radius * self.radius
+    }
+}
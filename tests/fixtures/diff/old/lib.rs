@@ -0,0 +1,10 @@
+pub struct Canvas {
+    shapes: Vec<Circle>,
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub struct Pen {
+}
@@ -0,0 +1,10 @@
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+pub struct Pair<K, V>(pub K, pub V);
+
+pub enum Slot<T> {
+    Empty,
+    Full(T),
+}
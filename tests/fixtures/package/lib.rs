@@ -0,0 +1,6 @@
+mod model;
+mod view;
+
+pub struct App {
+    list: view::List,
+}
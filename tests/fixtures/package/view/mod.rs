@@ -0,0 +1,7 @@
+mod row;
+
+use crate::model::Item;
+
+pub struct List {
+    items: Vec<Item>,
+}
@@ -0,0 +1,5 @@
+pub struct Brush {
+}
+
+struct Stroke {
+}
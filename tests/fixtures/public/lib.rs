@@ -0,0 +1,18 @@
+mod shape;
+pub mod draw;
+
+pub use shape::Circle as Round;
+
+pub struct Canvas {
+    pub shapes: Vec<Round>,
+    pens: Vec<shape::Pen>,
+    brush: draw::Brush,
+}
+
+impl Canvas {
+    pub fn draw(&self) {
+    }
+
+    fn clear(&mut self) {
+    }
+}
@@ -0,0 +1,6 @@
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub struct Pen {
+}
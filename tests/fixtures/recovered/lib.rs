@@ -0,0 +1,8 @@
+pub struct Recovered {
+    field: u8,
+}
+
+pub fn recover() {
+    let a = 1
+    let b = 2;
+}
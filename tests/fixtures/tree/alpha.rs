@@ -0,0 +1,4 @@
+mod nested;
+
+pub struct Alpha {
+}
@@ -0,0 +1,8 @@
+mod alpha;
+mod beta;
+#[path = "custom/gamma_impl.rs"]
+mod gamma;
+
+pub struct Root {
+    alpha: alpha::Alpha,
+}
@@ -0,0 +1,5 @@
+struct Two {
+}
+
+fn main() {
+}
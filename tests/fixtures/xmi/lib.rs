@@ -0,0 +1,10 @@
+mod shape;
+
+pub struct Canvas {
+    shapes: Vec<shape::Circle>,
+}
+
+impl Canvas {
+    pub fn draw(&self, pen: &shape::Pen) {
+    }
+}
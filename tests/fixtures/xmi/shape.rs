@@ -0,0 +1,16 @@
+pub trait Area {
+    fn area(&self) -> f64;
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+impl Area for Circle {
+    fn area(&self) -> f64 {
+        self.radius * self.radius
+    }
+}
+
+pub struct Pen {
+}
@@ -0,0 +1,21 @@
+mod parse {
+    pub struct Error {
+    }
+
+    pub struct Parser {
+        error: Error,
+    }
+
+    pub trait Check {
+        fn check(&self, error: Error) -> bool;
+    }
+}
+
+mod lex {
+    pub struct Error {
+    }
+
+    pub struct Lexer {
+        error: Error,
+    }
+}
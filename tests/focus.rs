@@ -0,0 +1,68 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Config, Direction, Focus, Model, Renderer};
+
+#[test]
+fn test_focus() {
+    let mut config: Config = Config::default();
+
+    config.focus = Some(Focus::from((&["Canvas"][..], 0, Direction::Both)));
+    let dot: String = String::from_utf8(Renderer::from(config).src2dot("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(dot.contains("    ndCanvas[label=<"));
+    // `Circle` is one relation beyond the hops.
+    assert!(dot.contains("    ndshape__Circle[label=\"Circle\"][style=\"dashed\"][color=\"grey\"][shape=\"box\"];"));
+    assert!(dot.contains("    ndshape__Circle -> ndCanvas[label=\"\"]"));
+    assert!(!dot.contains("ndshape__Area"));
+}
+
+#[test]
+fn test_focus_formats() {
+    let mut config: Config = Config::default();
+
+    config.focus = Some(Focus::from((&["Canvas"][..], 0, Direction::Both)));
+    let renderer: Renderer = Renderer::from(config);
+    let model: Model = rust2uml::parse_dir("tests/fixtures/xmi").unwrap();
+    let uml: String = String::from_utf8(renderer.model2plantuml(&model).unwrap()).unwrap();
+
+    assert!(uml.contains("class \"Canvas\" as ndCanvas"));
+    assert!(!uml.contains("ndshape__Area"));
+
+    let mermaid: String = String::from_utf8(renderer.model2mermaid(&model).unwrap()).unwrap();
+
+    assert!(mermaid.contains("ndCanvas"));
+    assert!(!mermaid.contains("ndshape__Area"));
+}
+
+#[test]
+fn test_focus_direction() {
+    let model: Model = rust2uml::parse_dir("tests/fixtures/xmi").unwrap();
+    let path = |segments: &[&str]| segments.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+
+    let outgoing: Model = model.focus(&Focus::from((&["shape::Circle"][..], 1, Direction::Outgoing))).unwrap();
+    assert!(outgoing.node(&path(&["", "shape", "Area"])).is_some());
+    assert!(outgoing.node(&path(&["", "Canvas"])).is_none());
+    assert!(outgoing.stubs.is_empty());
+
+    let incoming: Model = model.focus(&Focus::from((&["shape::Area"][..], 0, Direction::Incoming))).unwrap();
+    assert_eq!(incoming.stubs, vec![path(&["", "shape", "Circle"])]);
+    assert!(incoming.node(&path(&["", "Canvas"])).is_none());
+
+    assert!(model.focus(&Focus::from((&["Unknown"][..], 1, Direction::Both))).is_err());
+}
+
+#[test]
+fn test_focus_ambiguous() {
+    let model: Model = rust2uml::parse_dir("tests/fixtures/xmi_scope").unwrap();
+
+    match model.focus(&Focus::from((&["Error"][..], 1, Direction::Both))) {
+        Err(rust2uml::Error::Model(message)) => {
+            assert!(message.contains("parse::Error"));
+            assert!(message.contains("lex::Error"));
+        }
+        _ => panic!("an ambiguous type was expected"),
+    }
+    assert!(model.focus(&Focus::from((&["lex::Error"][..], 1, Direction::Both))).is_ok());
+}
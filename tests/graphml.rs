@@ -0,0 +1,22 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_graphml() {
+    let graphml: String = String::from_utf8(rust2uml::src2graphml("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(graphml.contains("<key id=\"relation\" for=\"edge\" attr.name=\"relation\" attr.type=\"string\"/>"));
+    assert!(graphml.contains(concat!(
+        "    <node id=\"ndshape__Circle\">\n",
+        "      <data key=\"name\">Circle</data>\n",
+        "      <data key=\"kind\">struct</data>\n",
+        "      <data key=\"krate\"></data>\n",
+        "      <data key=\"module\">shape</data>\n",
+        "      <data key=\"visibility\">pub</data>\n",
+        "      <data key=\"params\">0</data>\n",
+        "      <data key=\"fields\">1</data>\n",
+    )));
+    assert!(graphml.contains("source=\"ndshape__Circle\" target=\"ndCanvas\">\n      <data key=\"relation\">composition</data>\n"));
+    assert!(graphml.contains("source=\"ndshape__Area\" target=\"ndshape__Circle\">\n      <data key=\"relation\">realization</data>\n"));
+}
@@ -0,0 +1,78 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+#![cfg(unix)]
+extern crate rust2uml;
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use rust2uml::graphviz::{Engine, Graphviz, Output};
+use rust2uml::Error;
+
+/// The function `script` returns a fake graphviz binary running `body`.
+fn script(name: &str, body: &str) -> PathBuf {
+    let path: PathBuf = std::env::temp_dir().join(name);
+
+    fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+#[test]
+fn test_graphviz_names() {
+    assert_eq!("cmapx".parse::<Output>().unwrap(), Output::Cmapx);
+    assert_eq!("neato".parse::<Engine>().unwrap(), Engine::Neato);
+    assert!("gif".parse::<Output>().is_err());
+}
+
+// The fake binaries are written before any of them runs: a binary being written while
+// another is spawned can't be executed ("text file busy").
+#[test]
+fn test_graphviz_binary() {
+    let args: PathBuf = script("rust2uml_args_dot", "echo \"$@\"");
+    let echo: PathBuf = script("rust2uml_echo_dot", "cat");
+    let fail: PathBuf = script("rust2uml_fail_dot", "echo 'Error: syntax error in line 1' >&2; exit 3");
+    let slow: PathBuf = script("rust2uml_slow_dot", "echo slow >&2\nsleep 5 &\nwait");
+    let mut graphviz: Graphviz = Graphviz::default();
+
+    graphviz.binary = args;
+    graphviz.engine = Engine::Sfdp;
+    assert_eq!(
+        graphviz.render(b"digraph ml {}", Output::Pdf).unwrap(),
+        b"-Ksfdp -Tpdf\n"
+    );
+
+    // more than the capacity of the pipes, in and out.
+    let graph: Vec<u8> = vec![b'x'; 4 << 20];
+
+    graphviz.binary = echo;
+    graphviz.timeout = Some(Duration::from_secs(30));
+    assert_eq!(graphviz.render(&graph, Output::Svg).unwrap().len(), graph.len());
+
+    graphviz.binary = fail;
+    match graphviz.render(b"digraph", Output::Svg) {
+        Err(Error::Graphviz { code, stderr }) => {
+            assert_eq!(code, Some(3));
+            assert_eq!(stderr, "Error: syntax error in line 1");
+        }
+        result => panic!("unexpected {:?}", result),
+    }
+
+    graphviz.binary = slow;
+    graphviz.timeout = Some(Duration::from_millis(100));
+    let start: Instant = Instant::now();
+    match graphviz.render(b"digraph ml {}", Output::Svg) {
+        Err(Error::Timeout { timeout, stderr }) => {
+            assert_eq!(timeout, Duration::from_millis(100));
+            assert_eq!(stderr, "slow");
+        }
+        result => panic!("unexpected {:?}", result),
+    }
+    // the `sleep` started by the binary is killed with it.
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    graphviz.binary = PathBuf::from("/nonexistent/dot");
+    assert!(graphviz.render(b"digraph ml {}", Output::Svg).is_err());
+}
@@ -0,0 +1,29 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Backend, Config, Renderer};
+
+#[test]
+fn test_html_builtin() {
+    let mut config: Config = Config::default();
+
+    config.src_url_mask = "https://example.org/{file}".to_string();
+    let mut renderer: Renderer = Renderer::from(config);
+
+    renderer.set_backend(Backend::Builtin);
+    let html: String = String::from_utf8(renderer.src2html("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>\n"));
+    // the svg is inline, without its XML declaration.
+    assert!(!html.contains("<?xml"));
+    assert!(html.contains("<div id=\"ml-canvas\">\n<svg "));
+    assert!(html.contains("<input id=\"ml-search\""));
+    assert!(html.contains("\"ndshape__Circle\":{\"name\":\"Circle\",\"path\":\"shape::Circle\",\"kind\":\"struct\""));
+    assert!(html.contains("\"fields\":[\"pub radius: f64\"]"));
+    assert!(html.contains("\"url\":\"https://example.org/"));
+    // nothing is loaded from the network.
+    assert!(!html.contains("<script src"));
+    assert!(!html.contains("<link "));
+    assert!(html.ends_with("</script>\n</body>\n</html>\n"));
+}
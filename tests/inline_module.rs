@@ -0,0 +1,29 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+#![allow(dead_code)]
+extern crate rust2uml;
+
+struct Outer {
+}
+
+mod inner {
+    pub struct Inner {
+    }
+
+    mod deeper {
+        pub enum Deeper {
+            A,
+        }
+    }
+}
+
+#[test]
+fn test_inline_module() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let dot: String = String::from_utf8(rust2uml::rs2dot("tests/inline_module.rs").unwrap()).unwrap();
+
+    assert!(dot.contains("<b>Outer</b>"));
+    assert!(dot.contains("<b>Inner</b>"));
+    assert!(dot.contains("<b>Deeper</b>"));
+}
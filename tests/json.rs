@@ -0,0 +1,38 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::prelude::Relation;
+
+#[test]
+fn test_json() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let model: rust2uml::Model = rust2uml::parse_dir("tests/fixtures/tree").unwrap();
+    let json: String = model.to_json().unwrap();
+
+    assert!(json.contains("\"version\": 1"));
+    assert!(json.contains("\"relation\": \"composition\""));
+    assert_eq!(rust2uml::Model::from_json(&json).unwrap(), model);
+    assert!(rust2uml::Model::from_json(&json.replace("\"version\": 1", "\"version\": 99")).is_err());
+    assert!(rust2uml::Model::from_json("{\"version\": 1, \"packages\": [], \"nodes\": [{\"path\": [], \"kind\": \"struct\", \"vis\": \"public\", \"params\": [], \"fields\": [], \"variants\": [], \"items\": [], \"methods\": [], \"implems\": [], \"span\": null}], \"edges\": []}").is_err());
+
+    let dot: String = String::from_utf8(rust2uml::model2dot(&model).unwrap()).unwrap();
+    let src: String = String::from_utf8(rust2uml::src2dot("tests/fixtures/tree").unwrap()).unwrap();
+
+    assert_eq!(dot, src);
+}
+
+#[test]
+fn test_json_merge() {
+    let mut model: rust2uml::Model = rust2uml::parse_dir("tests/fixtures/tree").unwrap();
+    let mut other: rust2uml::Model = model.clone();
+    let edges: usize = model.edges.len();
+
+    other.edges[0].relation = match other.edges[0].relation {
+        Relation::Dependency => Relation::Association,
+        _ => Relation::Dependency,
+    };
+    model.merge(other);
+    assert_eq!(model.edges.len(), edges + 1);
+}
@@ -0,0 +1,30 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+#![allow(dead_code)]
+extern crate rust2uml;
+
+pub struct List<T> {
+    items: Vec<T>,
+    pub cursor: Option<Cursor>,
+}
+
+pub struct Cursor {
+}
+
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[test]
+fn test_mermaid() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let mermaid: String = String::from_utf8(rust2uml::rs2mermaid("tests/mermaid.rs").unwrap()).unwrap();
+
+    assert!(mermaid.starts_with("classDiagram\n"));
+    assert!(mermaid.contains("    class ndmermaid__List~T~[\"List\"] {\n        -items: Vec~T~\n        +cursor: Option~Cursor~\n    }\n"));
+    assert!(mermaid.contains("    class ndmermaid__Either~L, R~[\"Either\"] {\n        <<enumeration>>\n        Left: L\n"));
+    assert!(mermaid.contains("    ndmermaid__List *-- ndmermaid__Cursor\n"));
+    assert!(!mermaid.replace("<<enumeration>>", "").contains('<'));
+}
@@ -0,0 +1,49 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::model::{Kind, Visibility};
+use rust2uml::prelude::Relation;
+
+#[test]
+fn test_model() {
+    let model: rust2uml::Model = rust2uml::parse_crate("tests/fixtures/tree/lib.rs").unwrap();
+    let path = |segments: &[&str]| segments.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+
+    let root = model.node(&path(&["", "Root"])).unwrap();
+    assert_eq!(root.kind, Kind::Struct);
+    assert_eq!(root.vis, Visibility::Public);
+    assert_eq!(root.fields[0].name, Some("alpha".to_string()));
+    assert_eq!(root.fields[0].ty, "alpha::Alpha");
+    assert_eq!(root.span.as_ref().map(|span| span.line), Some(6));
+
+    let nested = model.node(&path(&["", "alpha", "nested", "Nested"])).unwrap();
+    assert_eq!(nested.module(), path(&["alpha", "nested"]).as_slice());
+    assert!(model.node(&path(&["", "gamma", "Gamma"])).is_some());
+    assert!(model.node(&path(&["", "Dead"])).is_none());
+
+    assert!(model.packages.iter().any(|package| package.path == path(&["", "beta"])));
+    assert!(model.edges.iter().any(|edge| {
+        edge.source == path(&["", "alpha", "Alpha"]) && edge.target == path(&["", "Root"]) && edge.relation == Relation::Composition
+    }));
+}
+
+#[test]
+fn test_model_generics() {
+    let model: rust2uml::Model = rust2uml::parse_dir("tests/fixtures/generic").unwrap();
+    let path = |segments: &[&str]| segments.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+
+    assert_eq!(model.node(&path(&["", "Stack"])).unwrap().params, vec!["T".to_string()]);
+    assert_eq!(model.node(&path(&["", "Pair"])).unwrap().params, vec!["K".to_string(), "V".to_string()]);
+    assert_eq!(model.node(&path(&["", "Slot"])).unwrap().params, vec!["T".to_string()]);
+
+    let renderer: rust2uml::Renderer = rust2uml::Renderer::default();
+    let uml: String = String::from_utf8(renderer.model2plantuml(&model).unwrap()).unwrap();
+
+    assert!(uml.contains("class \"Stack<T>\" as ndStack"));
+    assert!(uml.contains("class \"Pair<K, V>\" as ndPair"));
+
+    let xmi: String = String::from_utf8(renderer.model2xmi(&model).unwrap()).unwrap();
+
+    assert!(xmi.contains("xmi:id=\"ndStack.sig\""));
+}
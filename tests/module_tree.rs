@@ -0,0 +1,19 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_module_tree() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let dot: String = String::from_utf8(rust2uml::src2dot("tests/fixtures/tree").unwrap()).unwrap();
+
+    assert!(dot.contains("<b>Root</b>"));
+    assert!(dot.contains("<b>Alpha</b>"));
+    assert!(dot.contains("<b>Nested</b>"));
+    assert!(dot.contains("<b>Beta</b>"));
+    assert!(dot.contains("<b>Gamma</b>"));
+    assert!(dot.contains("tests/fixtures/tree/alpha/nested.rs"));
+    assert!(dot.contains("tests/fixtures/tree/custom/gamma_impl.rs"));
+    assert!(!dot.contains("<b>Dead</b>"));
+}
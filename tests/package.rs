@@ -0,0 +1,21 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::Renderer;
+
+#[test]
+fn test_package() {
+    let renderer: Renderer = Renderer::default();
+    let dot: String = String::from_utf8(renderer.src2package("tests/fixtures/package").unwrap()).unwrap();
+
+    assert!(dot.starts_with("digraph ml {\n"));
+    assert!(dot.contains("    subgraph cluster_ndcrate {\n        label=\"crate\";\n"));
+    assert!(dot.contains("        subgraph cluster_ndview {\n            label=\"view\";\n"));
+    assert!(dot.contains("            ndview__row[label=\"row\"][tooltip=\"view::row\"];\n"));
+    assert!(dot.contains("        ndmodel[label=\"model\"][tooltip=\"model\"];\n"));
+    // the composition of `List` and the `use` of `Item`.
+    assert!(dot.contains("    ndview -> ndmodel[label=\"2\"][weight=2]"));
+    assert!(dot.contains("    ndcrate -> ndview[label=\"1\"][weight=1]"));
+    assert!(!dot.contains("ndview__row ->"));
+}
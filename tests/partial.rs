@@ -0,0 +1,39 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_partial() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    match rust2uml::src2dot("tests/fixtures/broken") {
+        Err(rust2uml::Error::Parse { file, line, .. }) => {
+            assert!(file.ends_with("bad.rs"));
+            assert!(line >= 1);
+        }
+        _ => panic!("a parse error was expected"),
+    }
+
+    let (dot, diagnostics) = rust2uml::src2dot_partial("tests/fixtures/broken").unwrap();
+
+    assert!(String::from_utf8(dot).unwrap().contains("<b>Good</b>"));
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+fn test_partial_recovered() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    match rust2uml::src2dot("tests/fixtures/recovered") {
+        Err(rust2uml::Error::Parse { file, line, .. }) => {
+            assert!(file.ends_with("lib.rs"));
+            assert!(line >= 6);
+        }
+        _ => panic!("the recovered parse error was expected"),
+    }
+
+    let (dot, diagnostics) = rust2uml::src2dot_partial("tests/fixtures/recovered").unwrap();
+
+    assert!(String::from_utf8(dot).unwrap().contains("<b>Recovered</b>"));
+    assert_eq!(diagnostics.len(), 1);
+}
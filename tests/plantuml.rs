@@ -0,0 +1,40 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+#![allow(dead_code)]
+extern crate rust2uml;
+
+pub struct Holder {
+    pub part: Part,
+    shared: *const Shared,
+}
+
+struct Part {
+}
+
+struct Shared {
+}
+
+trait Speak {
+    fn speak(&self) -> String;
+}
+
+impl Speak for Holder {
+    fn speak(&self) -> String {
+        String::new()
+    }
+}
+
+#[test]
+fn test_plantuml() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let uml: String = String::from_utf8(rust2uml::rs2plantuml("tests/plantuml.rs").unwrap()).unwrap();
+
+    assert!(uml.starts_with("@startuml\n"));
+    assert!(uml.ends_with("@enduml\n"));
+    assert!(uml.contains("class \"Holder\" as ndplantuml__Holder {\n  +part: Part\n  -shared: *const Shared\n"));
+    assert!(uml.contains("interface \"Speak\" as ndplantuml__Speak {\n  +speak(&Self): String\n"));
+    assert!(uml.contains("ndplantuml__Holder *-- ndplantuml__Part\n"));
+    assert!(uml.contains("ndplantuml__Holder o-- ndplantuml__Shared\n"));
+    assert!(uml.contains("ndplantuml__Holder ..|> ndplantuml__Speak\n"));
+}
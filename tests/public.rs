@@ -0,0 +1,40 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Config, Model, Renderer};
+
+#[test]
+fn test_public_api() {
+    let mut config: Config = Config::default();
+
+    config.public_api = true;
+    let dot: String = String::from_utf8(Renderer::from(config).src2dot("tests/fixtures/public").unwrap()).unwrap();
+
+    assert!(dot.contains("    ndCanvas[label=<"));
+    assert!(dot.contains("    nddraw__Brush[label=<"));
+    // `shape::Circle` is seen through its re-export.
+    assert!(dot.contains("    ndRound[label=<"));
+    assert!(dot.contains("ndRound -> ndCanvas"));
+    assert!(!dot.contains("ndshape__"));
+    assert!(!dot.contains("Stroke"));
+    assert!(dot.contains(" draw("));
+    assert!(!dot.contains(" clear("));
+    assert!(!dot.contains("pens"));
+    // the private field `brush` doesn't draw its composition.
+    assert!(!dot.contains("brush"));
+    assert!(!dot.contains("nddraw__Brush -> ndCanvas"));
+}
+
+#[test]
+fn test_public_api_model() {
+    let model: Model = rust2uml::parse_dir("tests/fixtures/public").unwrap();
+    let path = |segments: &[&str]| segments.iter().map(|segment| segment.to_string()).collect::<Vec<String>>();
+    let public: Model = model.public_api();
+
+    assert!(model.node(&path(&["", "shape", "Pen"])).is_some());
+    assert!(public.node(&path(&["", "shape", "Pen"])).is_none());
+    assert!(public.node(&path(&["", "draw", "Stroke"])).is_none());
+    assert_eq!(public.node(&path(&["", "Canvas"])).map(|node| node.fields.len()), Some(1));
+    assert!(public.packages.iter().all(|package| package.path != path(&["", "shape"])));
+}
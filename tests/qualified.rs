@@ -0,0 +1,57 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+#![allow(dead_code)]
+extern crate rust2uml;
+
+mod a {
+    pub struct Error {
+    }
+
+    impl Error {
+        pub fn from_a() {
+        }
+    }
+}
+
+mod b {
+    pub struct Error {
+    }
+
+    impl Error {
+        pub fn from_b() {
+        }
+    }
+
+    pub struct User {
+        error: super::a::Error,
+    }
+}
+
+mod c {
+    use super::a::Error as AError;
+
+    pub struct Holder {
+        error: AError,
+    }
+}
+
+#[test]
+fn test_qualified() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let dot: String = String::from_utf8(rust2uml::rs2dot("tests/qualified.rs").unwrap()).unwrap();
+    let node = |id: &str| {
+        dot.lines()
+            .find(|line| line.trim_start().starts_with(&format!("{}[", id)))
+            .unwrap()
+            .to_string()
+    };
+
+    assert!(node("ndqualified__a__Error").contains("from_a()"));
+    assert!(!node("ndqualified__a__Error").contains("from_b()"));
+    assert!(node("ndqualified__b__Error").contains("from_b()"));
+    assert!(!node("ndqualified__b__Error").contains("from_a()"));
+    assert!(dot.contains("ndqualified__a__Error -> ndqualified__b__User"));
+    assert!(dot.contains("ndqualified__a__Error -> ndqualified__c__Holder"));
+    assert!(!dot.contains("ndqualified__b__Error -> ndqualified__b__User"));
+}
@@ -0,0 +1,30 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+#![allow(dead_code)]
+extern crate rust2uml;
+
+struct A {
+    field: u8,
+}
+
+impl A {
+    fn method() {
+    }
+}
+
+#[test]
+fn test_renderer() {
+    let mut config = rust2uml::Config::default();
+
+    config.include_fields = false;
+    config.include_methods = false;
+
+    let bare: String = String::from_utf8(rust2uml::Renderer::from(config).rs2dot("tests/renderer.rs").unwrap()).unwrap();
+    let full: String = String::from_utf8(rust2uml::Renderer::default().rs2dot("tests/renderer.rs").unwrap()).unwrap();
+
+    assert!(bare.contains("<b>A</b>"));
+    assert!(!bare.contains("field: u8"));
+    assert!(!bare.contains("method()"));
+    assert!(full.contains("field: u8"));
+    assert!(full.contains("method()"));
+}
@@ -0,0 +1,25 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_structurizr() {
+    let dsl: String = String::from_utf8(rust2uml::src2structurizr("tests/fixtures/xmi", false).unwrap()).unwrap();
+
+    assert!(dsl.contains("            container_crate = container \"crate\" \"Rust crate\" \"Rust\" {\n"));
+    assert!(dsl.contains("                component_crate__crate = component \"crate\" \"1 type\" \"Rust module\"\n"));
+    assert!(dsl.contains("                component_crate__shape = component \"shape\" \"3 types\" \"Rust module\"\n"));
+    assert!(dsl.contains("        component_crate__crate -> component_crate__shape \""));
+    assert!(dsl.contains("composition"));
+    assert!(!dsl.contains("ndshape__Circle"));
+    assert!(dsl.contains("        component container_crate {\n"));
+
+    let code: String = String::from_utf8(rust2uml::src2structurizr("tests/fixtures/xmi", true).unwrap()).unwrap();
+
+    // the types are properties of their module, not components of the view.
+    assert!(code.contains("                component_crate__shape = component \"shape\" \"3 types\" \"Rust module\" {\n                    properties {\n"));
+    assert!(code.contains("                        \"shape::Circle\" \"struct\"\n"));
+    assert!(code.contains("                        \"shape::Circle -> shape::Area\" \"realization\"\n"));
+    assert!(code.contains("                \"Canvas -> shape::Circle\" \"composition\"\n"));
+    assert!(!code.contains("ndshape__Circle"));
+}
@@ -0,0 +1,33 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Backend, Renderer};
+
+#[test]
+fn test_svg_builtin() {
+    let mut renderer: Renderer = Renderer::default();
+
+    renderer.set_backend(Backend::Builtin);
+    let svg: String = String::from_utf8(renderer.src2svg("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(svg.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\n<svg "));
+    assert!(svg.contains("<g id=\"ndshape__Circle\" class=\"node\">\n<title>shape::Circle</title>\n"));
+    assert!(svg.contains("font-weight=\"bold\" text-anchor=\"middle\">Circle</text>"));
+    assert!(svg.contains("text-anchor=\"start\">+ radius: f64</text>"));
+    assert!(svg.contains("class=\"edge\" data-source=\"ndshape__Circle\" data-target=\"ndCanvas\">"));
+    // the filled diamond of the composition and the open triangle of the realization.
+    assert!(svg.contains("fill=\"black\" stroke=\"black\"/>"));
+    assert!(svg.contains("stroke-dasharray=\"5,2\"/>\n<polygon "));
+    assert!(svg.ends_with("</g>\n</svg>\n"));
+}
+
+#[test]
+fn test_svg_both_builtin() {
+    let mut renderer: Renderer = Renderer::default();
+
+    renderer.set_backend(Backend::Builtin);
+    renderer.src2both("tests/fixtures/xmi", "target/test_svg_both").unwrap();
+    assert!(std::fs::read_to_string("target/test_svg_both/ml.dot").unwrap().starts_with("digraph ml {"));
+    assert!(std::fs::read_to_string("target/test_svg_both/ml.svg").unwrap().contains("<title>Canvas</title>"));
+}
@@ -0,0 +1,38 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+use rust2uml::{Config, Direction, Focus, Renderer};
+
+#[test]
+fn test_text() {
+    let renderer: Renderer = Renderer::default();
+    let text: String = String::from_utf8(renderer.src2text("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(text.starts_with("mod crate\n┌"));
+    assert!(text.contains("│ -shapes: Vec<shape::Circle>"));
+    assert!(text.contains("\nmod shape\n"));
+    assert!(text.contains("│    Circle    │\n├──────────────┤\n│ +radius: f64 │\n"));
+    assert!(text.contains("«trait»"));
+    assert!(text.contains("│ +area("));
+    assert!(text.contains("\nCanvas *-- shape::Circle (composition)\n"));
+    assert!(text.contains("\nshape::Circle ..|> shape::Area (realization)\n"));
+}
+
+#[test]
+fn test_text_focus() {
+    let mut config: Config = Config::default();
+
+    config.focus = Some(Focus::from((&["Canvas", "shape::Area"][..], 0, Direction::Outgoing)));
+    let text: String = String::from_utf8(Renderer::from(config).src2text("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(text.contains("Canvas"));
+    assert!(text.contains("Area"));
+    // `Circle` is a stub of `Canvas`, `Pen` too.
+    assert!(text.contains("Circle"));
+
+    let mut config: Config = Config::default();
+
+    config.focus = Some(Focus::from((&["Unknown"][..], 1, Direction::Both)));
+    assert!(Renderer::from(config).src2text("tests/fixtures/xmi").is_err());
+}
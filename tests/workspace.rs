@@ -0,0 +1,40 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_workspace() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let dots: Vec<(String, Vec<u8>)> = rust2uml::workspace2dots("tests/fixtures/workspace").unwrap();
+
+    assert_eq!(
+        dots.iter().map(|&(ref krate, _)| krate.as_str()).collect::<Vec<&str>>(),
+        vec!["one", "bin:two"]
+    );
+
+    let dot: String = String::from_utf8(rust2uml::workspace2dot("tests/fixtures/workspace/Cargo.toml").unwrap()).unwrap();
+
+    assert!(dot.contains("<b>One</b>"));
+    assert!(dot.contains("<b>Two</b>"));
+    assert!(dot.contains(">bin:two</font>"));
+    // the `:` of the tag is escaped, unlike a `_` of a crate `bin_two`.
+    assert!(dot.contains("    ndbin_3a_two__Two[label=<"));
+}
+
+#[test]
+fn test_workspace_glob() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let dots: Vec<(String, Vec<u8>)> = rust2uml::workspace2dots("tests/fixtures/workspace_glob").unwrap();
+
+    assert_eq!(
+        dots.iter().map(|&(ref krate, _)| krate.as_str()).collect::<Vec<&str>>(),
+        vec!["three", "tool_a"]
+    );
+
+    match rust2uml::workspace2dot("tests/fixtures/workspace_glob_invalid") {
+        Err(rust2uml::Error::Config(message)) => assert!(message.contains("crates/[one")),
+        _ => panic!("a configuration error was expected"),
+    }
+}
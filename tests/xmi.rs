@@ -0,0 +1,32 @@
+#![feature(rustc_private)]
+#![feature(box_patterns)]
+extern crate rust2uml;
+
+#[test]
+fn test_xmi() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let xmi: String = String::from_utf8(rust2uml::src2xmi("tests/fixtures/xmi").unwrap()).unwrap();
+
+    assert!(xmi.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<xmi:XMI"));
+    assert!(xmi.contains("<packagedElement xmi:type=\"uml:Package\" xmi:id=\"pkshape\" name=\"shape\">"));
+    assert!(xmi.contains("<packagedElement xmi:type=\"uml:Interface\" xmi:id=\"ndshape__Area\" name=\"Area\" visibility=\"public\">"));
+    assert!(xmi.contains("<ownedAttribute xmi:type=\"uml:Property\" xmi:id=\"ndCanvas.f0\" name=\"shapes\" visibility=\"private\" type=\"dt0\"/>"));
+    assert!(xmi.contains("<packagedElement xmi:type=\"uml:DataType\" xmi:id=\"dt0\" name=\"Vec&lt;shape::Circle&gt;\"/>"));
+    assert!(xmi.contains("client=\"ndshape__Circle\" supplier=\"ndshape__Area\" contract=\"ndshape__Area\""));
+    assert!(xmi
+        .lines()
+        .any(|line| line.contains("type=\"ndshape__Circle\"") && line.contains("aggregation=\"composite\"")));
+}
+
+#[test]
+fn test_xmi_scope() {
+    rust2uml::Config::set_global(rust2uml::Config::default());
+
+    let xmi: String = String::from_utf8(rust2uml::src2xmi("tests/fixtures/xmi_scope").unwrap()).unwrap();
+
+    // each `Error` is the one of the module of its field.
+    assert!(xmi.contains("xmi:id=\"ndparse__Parser.f0\" name=\"error\" visibility=\"private\" type=\"ndparse__Error\"/>"));
+    assert!(xmi.contains("xmi:id=\"ndlex__Lexer.f0\" name=\"error\" visibility=\"private\" type=\"ndlex__Error\"/>"));
+    assert!(xmi.contains("xmi:id=\"ndparse__Check.m0.p1\" type=\"ndparse__Error\" direction=\"in\"/>"));
+}